@@ -0,0 +1,190 @@
+use std::fmt;
+
+use utils::{
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
+const TEXT_NUMBERS: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+// a line with nothing to read a calibration value from, counting lines from 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoDigit {
+    pub line: usize,
+}
+
+impl fmt::Display for NoDigit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {} has no digit", self.line)
+    }
+}
+
+impl std::error::Error for NoDigit {}
+
+// every line needs a digit for part 2, part 1 only counts the numeric ones
+pub fn parse<'a>(input: &[&'a str]) -> Result<Vec<&'a str>, ParseError> {
+    for line in parse::lines(input) {
+        let has_digit = line.text.contains(|c: char| c.is_ascii_digit())
+            || TEXT_NUMBERS.iter().any(|num| line.text.contains(num));
+        if !has_digit {
+            return Err(line.error(line.text, "a line with a digit, in numerals or words"));
+        }
+    }
+
+    Ok(input.to_vec())
+}
+
+pub fn solve1(input: &[&str]) -> Result<u32, NoDigit> {
+    let mut sum = 0;
+
+    for (idx, line) in input.iter().enumerate() {
+        let digits = || line.chars().filter_map(|c| c.to_digit(10));
+        let (Some(first), Some(last)) = (digits().next(), digits().next_back()) else {
+            return Err(NoDigit { line: idx + 1 });
+        };
+        let num = first * 10 + last;
+        sum += num;
+    }
+
+    Ok(sum)
+}
+
+pub fn solve2(input: &[&str]) -> Result<u32, NoDigit> {
+    let mut sum = 0;
+
+    for (idx, line) in input.iter().enumerate() {
+        let mut min_text_pos = usize::MAX;
+        let mut min_text_val = None;
+        let mut max_text_pos = usize::MIN;
+        let mut max_text_val = None;
+
+        for (i, txt_num) in TEXT_NUMBERS.iter().enumerate() {
+            if let Some(pos) = line.find(txt_num) {
+                if pos < min_text_pos {
+                    min_text_pos = pos;
+                    min_text_val = Some(i as u32);
+                }
+            }
+
+            if let Some(pos) = line.rfind(txt_num) {
+                if pos > max_text_pos {
+                    max_text_pos = pos;
+                    max_text_val = Some(i as u32);
+                }
+            }
+        }
+
+        let mut min_num_pos = usize::MAX;
+        let mut min_num_val = None;
+        let mut max_num_pos = usize::MIN;
+        let mut max_num_val = None;
+
+        for (pos, c) in line.chars().enumerate() {
+            if c.is_ascii_digit() {
+                if pos < min_num_pos {
+                    min_num_pos = pos;
+                    min_num_val = Some(c.to_digit(10).unwrap());
+                }
+
+                if pos > max_num_pos {
+                    max_num_pos = pos;
+                    max_num_val = Some(c.to_digit(10).unwrap());
+                }
+            }
+        }
+
+        let left = match (min_text_val, min_num_val) {
+            (None, None) => return Err(NoDigit { line: idx + 1 }),
+            (None, Some(val)) => val,
+            (Some(val), None) => val,
+            (Some(tval), Some(nval)) => {
+                if min_text_pos < min_num_pos {
+                    tval
+                } else {
+                    nval
+                }
+            }
+        };
+
+        let right = match (max_text_val, max_num_val) {
+            (None, None) => left,
+            (None, Some(val)) => val,
+            (Some(val), None) => val,
+            (Some(tval), Some(nval)) => {
+                if max_text_pos > max_num_pos {
+                    tval
+                } else {
+                    nval
+                }
+            }
+        };
+
+        sum += left * 10 + right;
+    }
+
+    Ok(sum)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    type Parsed<'a> = Vec<&'a str>;
+    type Params = ();
+    const PARAMS: () = ();
+
+    fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
+        parse(&input.lines().collect::<Vec<_>>())
+    }
+
+    fn part1(lines: &Vec<&str>, _: &()) -> Answer {
+        solve1(lines).into()
+    }
+
+    fn part2(lines: &Vec<&str>, _: &()) -> Answer {
+        solve2(lines).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[&str] = &["1abc2", "pqr3stu8vwx", "a1b2c3d4e5f", "treb7uchet"];
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve1(INPUT), Ok(142))
+    }
+
+    #[test]
+    fn test2() {
+        let part_2_input = &[
+            "two1nine",
+            "eightwothree",
+            "abcone2threexyz",
+            "xtwone3four",
+            "4nineeightseven2",
+            "zoneight234",
+            "7pqrstsixteen",
+        ];
+        assert_eq!(solve2(part_2_input), Ok(281))
+    }
+
+    #[test]
+    fn errors() {
+        let err = parse(&["1abc2", "abc"]).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "abc"));
+
+        // spelled out digits are fine when parsing, but not for part 1
+        let lines = parse(&["1abc2", "xtwone"]).unwrap();
+        assert_eq!(solve1(&lines), Err(NoDigit { line: 2 }));
+        assert_eq!(solve2(&lines), Ok(12 + 21));
+        assert_eq!(
+            Day01::part1(&lines, &()),
+            Answer::Error("line 2 has no digit".to_owned())
+        );
+    }
+}
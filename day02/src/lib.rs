@@ -0,0 +1,158 @@
+use utils::{
+    parse::{self, Line, ParseError},
+    Answer, Solution,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct GameSubset {
+    r: u32,
+    g: u32,
+    b: u32,
+}
+
+pub type Game = (u32, Vec<GameSubset>);
+
+fn parse_game(line: Line) -> Result<Game, ParseError> {
+    let mut subsets = Vec::new();
+    let (_, cur_line) = line.split_once(line.text, " ")?;
+    let (game_id, cur_line) = line.split_once(cur_line, ":")?;
+    let game_id = line.parse(game_id, "a game id")?;
+
+    for subset in cur_line.split(';') {
+        let mut r = 0;
+        let mut g = 0;
+        let mut b = 0;
+        for color_sample in subset.split(',').map(|c| c.trim()) {
+            let (num, color) = line.split_once(color_sample, " ")?;
+            let num = line.parse(num, "a number of cubes")?;
+            match color {
+                "red" => r = num,
+                "green" => g = num,
+                "blue" => b = num,
+                _ => return Err(line.error(color, "red, green or blue")),
+            }
+        }
+
+        subsets.push(GameSubset { r, g, b });
+    }
+
+    Ok((game_id, subsets))
+}
+
+pub fn parse(input: &[&str]) -> Result<Vec<Game>, ParseError> {
+    parse::lines(input).map(parse_game).collect()
+}
+
+pub fn solve1(games: &[Game]) -> u32 {
+    let max_red = 12;
+    let max_green = 13;
+    let max_blue = 14;
+
+    let mut valid_games_sum = 0;
+
+    for (game_id, subsets) in games {
+        let mut all_valid = true;
+        for subset in subsets {
+            if subset.r > max_red || subset.g > max_green || subset.b > max_blue {
+                all_valid = false;
+                break;
+            }
+        }
+
+        if all_valid {
+            valid_games_sum += *game_id;
+        }
+    }
+
+    valid_games_sum
+}
+
+pub fn solve2(games: &[Game]) -> u32 {
+    let mut sum = 0;
+
+    for (_, subsets) in games {
+        let mut min_red = 0;
+        let mut min_green = 0;
+        let mut min_blue = 0;
+
+        for subset in subsets {
+            min_red = min_red.max(subset.r);
+            min_green = min_green.max(subset.g);
+            min_blue = min_blue.max(subset.b);
+        }
+
+        let cube_power = min_red * min_green * min_blue;
+        sum += cube_power;
+    }
+
+    sum
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    type Parsed<'a> = Vec<Game>;
+    type Params = ();
+    const PARAMS: () = ();
+
+    fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+        parse(&input.lines().collect::<Vec<_>>())
+    }
+
+    fn part1(games: &Vec<Game>, _: &()) -> Answer {
+        solve1(games).into()
+    }
+
+    fn part2(games: &Vec<Game>, _: &()) -> Answer {
+        solve2(games).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[&str] = &[
+        "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+        "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue",
+        "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+        "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red",
+        "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green",
+    ];
+
+    #[test]
+    fn test_parse_game() {
+        let (game_id, subsets) = parse_game(Line::new(0, INPUT[0])).unwrap();
+        assert_eq!(game_id, 1);
+        assert_eq!(
+            &subsets,
+            &[
+                GameSubset { r: 4, g: 0, b: 3 },
+                GameSubset { r: 1, g: 2, b: 6 },
+                GameSubset { r: 0, g: 2, b: 0 }
+            ]
+        )
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = parse(&[INPUT[0], "Game 2: 3 blue, 4 purple"]).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 19));
+        assert_eq!(err.text, "purple");
+
+        let err = parse(&["Game x: 3 blue"]).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 6));
+    }
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve1(&parse(INPUT).unwrap()), 8)
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve2(&parse(&INPUT[0..1]).unwrap()), 48);
+        assert_eq!(solve2(&parse(INPUT).unwrap()), 2286)
+    }
+}
@@ -0,0 +1,164 @@
+use std::collections::HashSet;
+
+use utils::{parse::ParseError, Answer, Grid2D, Point, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GridNum {
+    val: u32,
+    num_chars: usize,
+    id: usize,
+}
+
+#[derive(Debug, Clone)]
+pub enum Token {
+    Empty,
+    Symbol(char),
+    Number(GridNum),
+}
+
+pub fn parse(input: &[&str]) -> Result<Grid2D<Token>, ParseError> {
+    fn resolve_buffered_numbers(num_buf: &mut Vec<u32>, cells: &mut Vec<Token>, id: usize) {
+        let mut num = 0;
+        for digit in num_buf.iter() {
+            num = num * 10 + digit;
+        }
+
+        for _ in 0..num_buf.len() {
+            cells.push(Token::Number(GridNum {
+                val: num,
+                num_chars: num_buf.len(),
+                id,
+            }));
+        }
+        num_buf.clear();
+    }
+
+    // any char is fine, this only checks the rows are the same length
+    let chars = Grid2D::parse(input, "a char", Some)?;
+
+    let mut num_id = 0;
+    let mut cells = Vec::new();
+    for row in chars.rows() {
+        let mut num_buf = Vec::new();
+        for &chr in row {
+            if let Some(digit) = chr.to_digit(10) {
+                num_buf.push(digit)
+            } else {
+                if !num_buf.is_empty() {
+                    resolve_buffered_numbers(&mut num_buf, &mut cells, num_id);
+                    num_id += 1;
+                }
+                if chr == '.' {
+                    cells.push(Token::Empty);
+                } else {
+                    cells.push(Token::Symbol(chr));
+                }
+            }
+        }
+        if !num_buf.is_empty() {
+            resolve_buffered_numbers(&mut num_buf, &mut cells, num_id);
+            num_id += 1;
+        }
+    }
+
+    Ok(Grid2D::from_cells(chars.width(), cells))
+}
+
+pub fn solve1(grid: &Grid2D<Token>) -> u32 {
+    let mut sum = 0;
+
+    for (y, row) in grid.rows().enumerate() {
+        let mut cur_num_id = None;
+        for (x, col) in row.iter().enumerate() {
+            if let Token::Number(grid_num) = col {
+                if cur_num_id.is_some() {
+                    continue;
+                }
+                cur_num_id = Some(grid_num.id);
+                if (x..x + grid_num.num_chars).any(|x_span| {
+                    grid.adjacents(Point { x: x_span, y })
+                        .any(|t| matches!(t.val, Token::Symbol(_)))
+                }) {
+                    sum += grid_num.val;
+                }
+            } else {
+                cur_num_id = None;
+            }
+        }
+    }
+
+    sum
+}
+
+pub fn solve2(grid: &Grid2D<Token>) -> u32 {
+    let mut sum = 0;
+
+    for (y, row) in grid.rows().enumerate() {
+        for (x, col) in row.iter().enumerate() {
+            if let Token::Symbol(sym) = col {
+                if *sym == '*' {
+                    let mut adjacent_grid_nums = HashSet::new();
+                    for adj in grid.adjacents(Point { x, y }) {
+                        if let Token::Number(grid_num) = adj.val {
+                            adjacent_grid_nums.insert(*grid_num);
+                        }
+                    }
+                    if adjacent_grid_nums.len() == 2 {
+                        sum += adjacent_grid_nums.into_iter().fold(1, |a, e| a * e.val);
+                    }
+                }
+            }
+        }
+    }
+
+    sum
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    type Parsed<'a> = Grid2D<Token>;
+    type Params = ();
+    const PARAMS: () = ();
+
+    fn parse(input: &str) -> Result<Grid2D<Token>, ParseError> {
+        parse(&input.lines().collect::<Vec<_>>())
+    }
+
+    fn part1(grid: &Grid2D<Token>, _: &()) -> Answer {
+        solve1(grid).into()
+    }
+
+    fn part2(grid: &Grid2D<Token>, _: &()) -> Answer {
+        solve2(grid).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[&str] = &[
+        "467..114..",
+        "...*......",
+        "..35..633.",
+        "......#...",
+        "617*......",
+        ".....+.58.",
+        "..592.....",
+        "......755.",
+        "...$.*....",
+        ".664.598..",
+    ];
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve1(&parse(INPUT).unwrap()), 4361)
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve2(&parse(INPUT).unwrap()), 467835)
+    }
+}
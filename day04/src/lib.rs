@@ -0,0 +1,146 @@
+use std::collections::{HashMap, HashSet};
+
+use utils::{
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
+pub struct Card {
+    id: u32,
+    winning_numbers: HashSet<u32>,
+    numbers: HashSet<u32>,
+}
+
+pub fn parse(input: &[&str]) -> Result<Vec<Card>, ParseError> {
+    let mut cards = Vec::new();
+    for line in parse::lines(input) {
+        let (_, rest) = line.split_once(line.text, " ")?;
+        let (id, rest) = line.split_once(rest, ":")?;
+        let id = line.parse(id.trim(), "a card id")?;
+        let (winning_numbers_str, numbers_str) = line.split_once(rest, "|")?;
+        let mut winning_numbers = HashSet::new();
+        for wnum in winning_numbers_str.split_ascii_whitespace() {
+            winning_numbers.insert(line.parse(wnum, "a number")?);
+        }
+
+        let mut numbers = HashSet::new();
+        for num in numbers_str.split_ascii_whitespace() {
+            numbers.insert(line.parse(num, "a number")?);
+        }
+
+        cards.push(Card {
+            id,
+            winning_numbers,
+            numbers,
+        });
+    }
+
+    Ok(cards)
+}
+
+pub fn solve1(cards: &[Card]) -> u32 {
+    let mut sum = 0;
+
+    for card in cards {
+        let num_winning = card.winning_numbers.intersection(&card.numbers).count();
+        if num_winning > 0 {
+            sum += 2_u32.pow(num_winning as u32 - 1);
+        }
+    }
+
+    sum
+}
+
+fn resolve_children(
+    cur_card: &Card,
+    cards: &HashMap<u32, &Card>,
+    num_children: &mut HashMap<u32, u32>,
+) {
+    let num_subcards = cur_card
+        .winning_numbers
+        .intersection(&cur_card.numbers)
+        .count() as u32;
+
+    // copies stop at the end of the table
+    let mut sum = 0;
+    for offset in 1..=num_subcards {
+        let Some(card) = cur_card.id.checked_add(offset).and_then(|i| cards.get(&i)) else {
+            break;
+        };
+        if !num_children.contains_key(&card.id) {
+            resolve_children(card, cards, num_children);
+        }
+        sum += 1 + num_children[&card.id];
+    }
+
+    num_children.insert(cur_card.id, sum);
+}
+
+pub fn solve2(cards: &[Card]) -> u32 {
+    let card_by_id: HashMap<u32, &Card> = HashMap::from_iter(cards.iter().map(|c| (c.id, c)));
+    let mut num_children: HashMap<u32, u32> = HashMap::new();
+
+    for card in card_by_id.values() {
+        resolve_children(card, &card_by_id, &mut num_children);
+    }
+
+    let mut sum = card_by_id.len();
+    for (card_id, _) in card_by_id.iter() {
+        sum += *num_children.get(card_id).unwrap_or(&0) as usize;
+    }
+
+    sum as u32
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+    type Parsed<'a> = Vec<Card>;
+    type Params = ();
+    const PARAMS: () = ();
+
+    fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+        parse(&input.lines().collect::<Vec<_>>())
+    }
+
+    fn part1(cards: &Vec<Card>, _: &()) -> Answer {
+        solve1(cards).into()
+    }
+
+    fn part2(cards: &Vec<Card>, _: &()) -> Answer {
+        solve2(cards).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[&str] = &[
+        "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53",
+        "Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19",
+        "Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1",
+        "Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83",
+        "Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36",
+        "Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11",
+    ];
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve1(&parse(INPUT).unwrap()), 13)
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve2(&parse(INPUT).unwrap()), 30)
+    }
+
+    #[test]
+    fn wins_past_the_end() {
+        let cards = parse(&["Card 1: 1 2 | 1 2", "Card 2: 3 | 4"]).unwrap();
+        assert_eq!(solve2(&cards), 3);
+        let cards = parse(&["Card 4294967295: 1 | 1"]).unwrap();
+        assert_eq!(solve2(&cards), 1);
+    }
+}
@@ -0,0 +1,612 @@
+use std::{collections::HashSet, fmt, ops::Range};
+
+use utils::{
+    parse::{self, ParseError},
+    pathfinding, Answer, Solution,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mapping {
+    input_range: Range<u64>,
+    output_range: Range<u64>,
+}
+
+// piecewise shift of ids, anything outside the pieces maps to itself. Covers every
+// id below u64::MAX
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeMap {
+    // sorted and non-overlapping, adjacent pieces have different offsets, none is 0.
+    // Offsets between any two u64s need more than an i64
+    pieces: Vec<(Range<u64>, i128)>,
+}
+
+// every piece maps u64 ids to u64 ids, composing and inverting keeps it that way
+fn shift(id: u64, offset: i128) -> u64 {
+    u64::try_from(id as i128 + offset).expect("ids stay within u64 when shifted")
+}
+
+impl RangeMap {
+    // overlapping mappings are resolved like `convert` does, the lowest input wins
+    pub fn from_mappings(mappings: &[Mapping]) -> Self {
+        let mut mappings = mappings.to_vec();
+        mappings.sort_unstable_by_key(|m| m.input_range.start);
+
+        let mut covered = 0;
+        Self::from_segments(mappings.iter().map(|m| {
+            let offset = m.output_range.start as i128 - m.input_range.start as i128;
+            let start = m.input_range.start.max(covered);
+            covered = covered.max(m.input_range.end);
+            (start..m.input_range.end, offset)
+        }))
+    }
+
+    // segments have to be sorted and non-overlapping
+    fn from_segments(segments: impl IntoIterator<Item = (Range<u64>, i128)>) -> Self {
+        let mut pieces: Vec<(Range<u64>, i128)> = Vec::new();
+        for (range, offset) in segments {
+            if range.is_empty() || offset == 0 {
+                continue;
+            }
+            match pieces.last_mut() {
+                Some((last, last_offset)) if last.end == range.start && *last_offset == offset => {
+                    last.end = range.end;
+                }
+                _ => pieces.push((range, offset)),
+            }
+        }
+
+        Self { pieces }
+    }
+
+    // the pieces with the gaps between them filled in, from 0 up to u64::MAX
+    fn segments(&self) -> Vec<(Range<u64>, i128)> {
+        let mut segments = Vec::new();
+        let mut start = 0;
+        for (range, offset) in &self.pieces {
+            if start < range.start {
+                segments.push((start..range.start, 0));
+            }
+            segments.push((range.clone(), *offset));
+            start = range.end;
+        }
+        if start < u64::MAX {
+            segments.push((start..u64::MAX, 0));
+        }
+
+        segments
+    }
+
+    pub fn get(&self, id: u64) -> u64 {
+        let idx = self.pieces.partition_point(|(range, _)| range.end <= id);
+        match self.pieces.get(idx) {
+            Some((range, offset)) if range.contains(&id) => shift(id, *offset),
+            _ => id,
+        }
+    }
+
+    // a single map doing `self` and then `then`
+    pub fn compose(&self, then: &RangeMap) -> RangeMap {
+        let then_segments = then.segments();
+        let mut composed = Vec::new();
+        for (range, offset) in self.segments() {
+            let image = shift(range.start, offset)..shift(range.end, offset);
+            let first = then_segments.partition_point(|(r, _)| r.end <= image.start);
+            for (then_range, then_offset) in &then_segments[first..] {
+                if then_range.start >= image.end {
+                    break;
+                }
+                let start = image.start.max(then_range.start);
+                let end = image.end.min(then_range.end);
+                composed.push((
+                    shift(start, -offset)..shift(end, -offset),
+                    offset + then_offset,
+                ));
+            }
+        }
+
+        Self::from_segments(composed)
+    }
+
+    // None if two ids map to the same one, so there's no way back
+    pub fn invert(&self) -> Option<RangeMap> {
+        let mut images = self
+            .segments()
+            .into_iter()
+            .map(|(range, offset)| {
+                (
+                    shift(range.start, offset)..shift(range.end, offset),
+                    -offset,
+                )
+            })
+            .collect::<Vec<_>>();
+        images.sort_unstable_by_key(|(range, _)| range.start);
+        if images.windows(2).any(|w| w[0].0.end > w[1].0.start) {
+            return None;
+        }
+
+        Some(Self::from_segments(images))
+    }
+
+    // lowest id any id in `range` maps to, None if it's empty
+    pub fn min_over(&self, range: Range<u64>) -> Option<u64> {
+        self.segments()
+            .into_iter()
+            .filter(|(segment, _)| segment.start < range.end && range.start < segment.end)
+            .map(|(segment, offset)| shift(segment.start.max(range.start), offset))
+            .min()
+    }
+}
+
+// one `X-to-Y map:` section
+#[derive(Debug)]
+pub struct CategoryMap<'a> {
+    pub source: &'a str,
+    pub destination: &'a str,
+    // sorted by input range
+    mappings: Vec<Mapping>,
+}
+
+// categories are nodes, every map is an edge from its source to its destination
+#[derive(Debug, Default)]
+pub struct Almanac<'a> {
+    maps: Vec<CategoryMap<'a>>,
+}
+
+impl<'a> Almanac<'a> {
+    pub fn categories(&self) -> impl Iterator<Item = &'a str> + '_ {
+        let mut seen = HashSet::new();
+        self.maps
+            .iter()
+            .flat_map(|map| [map.source, map.destination])
+            .filter(move |category| seen.insert(*category))
+    }
+
+    // the maps to go through, in order, with the fewest steps
+    pub fn path(&self, from: &str, to: &str) -> Option<Vec<&CategoryMap<'a>>> {
+        let search = pathfinding::bfs(
+            from,
+            |category| {
+                self.maps
+                    .iter()
+                    .filter(|map| map.source == *category)
+                    .map(|map| map.destination)
+                    .collect::<Vec<_>>()
+            },
+            |category| *category == to,
+        );
+        let categories = search.goal_path()?;
+
+        Some(
+            categories
+                .windows(2)
+                .map(|pair| {
+                    self.maps
+                        .iter()
+                        .find(|map| map.source == pair[0] && map.destination == pair[1])
+                        .unwrap()
+                })
+                .collect(),
+        )
+    }
+
+    pub fn convert(&self, id: u64, from: &str, to: &str) -> Option<u64> {
+        let path = self.path(from, to)?;
+        Some(path.iter().fold(id, |id, map| convert(id, &map.mappings)))
+    }
+
+    // every map on the path folded into one
+    pub fn range_map(&self, from: &str, to: &str) -> Option<RangeMap> {
+        let path = self.path(from, to)?;
+        Some(path.iter().fold(RangeMap::default(), |map, category| {
+            map.compose(&RangeMap::from_mappings(&category.mappings))
+        }))
+    }
+}
+
+pub fn parse<'a>(input: &[&'a str]) -> Result<(Vec<u64>, Almanac<'a>), ParseError> {
+    let mut lines = parse::lines(input).peekable();
+    let seed_line = lines
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(0, "a list of seeds"))?;
+    let seeds: Vec<u64> = seed_line
+        .split_once(seed_line.text, ":")?
+        .1
+        .split_ascii_whitespace()
+        .map(|n| seed_line.parse(n, "a seed number"))
+        .collect::<Result<_, _>>()?;
+
+    let mut almanac = Almanac::default();
+    loop {
+        // blank lines between sections
+        while lines.next_if(|line| line.text.trim().is_empty()).is_some() {}
+        let Some(header) = lines.next() else {
+            break;
+        };
+
+        let Some(names) = header.text.strip_suffix(" map:") else {
+            return Err(header.error(header.text, "a map header"));
+        };
+        let (source, destination) = header.split_once(names, "-to-")?;
+        if almanac
+            .maps
+            .iter()
+            .any(|map| map.source == source && map.destination == destination)
+        {
+            return Err(header.error(names, "a map not listed before"));
+        }
+
+        let mut mappings = Vec::new();
+        while let Some(line) = lines.next_if(|line| !line.text.trim().is_empty()) {
+            let (destination_range_start, remaining) = line.split_once(line.text.trim(), " ")?;
+            let destination_range_start: u64 = line.parse(destination_range_start, "a number")?;
+            let (source_range_start, remaining) = line.split_once(remaining.trim(), " ")?;
+            let source_range_start: u64 = line.parse(source_range_start, "a number")?;
+            let length: u64 = line.parse(remaining.trim(), "a number")?;
+
+            let (Some(source_range_end), Some(destination_range_end)) = (
+                source_range_start.checked_add(length),
+                destination_range_start.checked_add(length),
+            ) else {
+                return Err(line.error(line.text, "a mapping with ranges that stay below 2^64"));
+            };
+            mappings.push(Mapping {
+                input_range: source_range_start..source_range_end,
+                output_range: destination_range_start..destination_range_end,
+            });
+        }
+        if mappings.is_empty() {
+            return Err(ParseError::new(header.index + 2, 1, "", "a mapping"));
+        }
+        mappings.sort_unstable_by_key(|v| v.input_range.start);
+
+        almanac.maps.push(CategoryMap {
+            source,
+            destination,
+            mappings,
+        });
+    }
+
+    if almanac.path("seed", "location").is_none() {
+        return Err(ParseError::end_of_input(
+            input.len(),
+            "maps leading from seed to location",
+        ));
+    }
+
+    Ok((seeds, almanac))
+}
+
+fn convert(id: u64, mappings: &[Mapping]) -> u64 {
+    for mapping in mappings {
+        if mapping.input_range.contains(&id) {
+            let offset = id - mapping.input_range.start;
+            return mapping.output_range.start + offset;
+        }
+    }
+
+    id
+}
+
+pub fn solve1(seeds: &[u64], almanac: &Almanac) -> u64 {
+    let map = almanac
+        .range_map("seed", "location")
+        .expect("checked when parsing");
+    seeds
+        .iter()
+        .map(|seed| map.get(*seed))
+        .min()
+        .unwrap_or(u64::MAX)
+}
+
+// part 1 takes the seeds one by one, these only come up reading them as ranges
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeedRangeError {
+    // the last seed has no length
+    OddCount,
+    // a range that ends past u64::MAX
+    Overflow { start: u64, length: u64 },
+}
+
+impl fmt::Display for SeedRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SeedRangeError::OddCount => write!(f, "the last seed range has no length"),
+            SeedRangeError::Overflow { start, length } => {
+                write!(f, "the seed range {start} +{length} ends past 2^64")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SeedRangeError {}
+
+// the seeds as pairs of range start and length
+pub fn seed_ranges(seeds: &[u64]) -> Result<Vec<Range<u64>>, SeedRangeError> {
+    if !seeds.len().is_multiple_of(2) {
+        return Err(SeedRangeError::OddCount);
+    }
+    seeds
+        .chunks_exact(2)
+        .map(|pair| {
+            let (start, length) = (pair[0], pair[1]);
+            let end = start
+                .checked_add(length)
+                .ok_or(SeedRangeError::Overflow { start, length })?;
+            Ok(start..end)
+        })
+        .collect()
+}
+
+pub fn solve2(seeds: &[u64], almanac: &Almanac) -> Result<u64, SeedRangeError> {
+    let map = almanac
+        .range_map("seed", "location")
+        .expect("checked when parsing");
+    Ok(seed_ranges(seeds)?
+        .into_iter()
+        .filter_map(|range| map.min_over(range))
+        .min()
+        .unwrap_or(u64::MAX))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    type Parsed<'a> = (Vec<u64>, Almanac<'a>);
+    type Params = ();
+    const PARAMS: () = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(&input.lines().collect::<Vec<_>>())
+    }
+
+    fn part1((seeds, almanac): &Self::Parsed<'_>, _: &()) -> Answer {
+        solve1(seeds, almanac).into()
+    }
+
+    fn part2((seeds, almanac): &Self::Parsed<'_>, _: &()) -> Answer {
+        solve2(seeds, almanac).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use utils::rng::Lcg;
+
+    use super::*;
+
+    const INPUT: &[&str] = &[
+        "seeds: 79 14 55 13",
+        "",
+        "seed-to-soil map:",
+        "50 98 2",
+        "52 50 48",
+        "",
+        "soil-to-fertilizer map:",
+        "0 15 37",
+        "37 52 2",
+        "39 0 15",
+        "",
+        "fertilizer-to-water map:",
+        "49 53 8",
+        "0 11 42",
+        "42 0 7",
+        "57 7 4",
+        "",
+        "water-to-light map:",
+        "88 18 7",
+        "18 25 70",
+        "",
+        "light-to-temperature map:",
+        "45 77 23",
+        "81 45 19",
+        "68 64 13",
+        "",
+        "temperature-to-humidity map:",
+        "0 69 1",
+        "1 0 69",
+        "",
+        "humidity-to-location map:",
+        "60 56 37",
+        "56 93 4",
+    ];
+
+    #[test]
+    fn test1() {
+        let (seeds, almanac) = parse(INPUT).unwrap();
+        assert_eq!(solve1(&seeds, &almanac), 35)
+    }
+
+    #[test]
+    fn any_conversion() {
+        let (_, almanac) = parse(INPUT).unwrap();
+        let path = almanac.path("soil", "humidity").unwrap();
+        assert_eq!(
+            path.iter().map(|m| m.destination).collect::<Vec<_>>(),
+            ["fertilizer", "water", "light", "temperature", "humidity"]
+        );
+        // seed 79 goes to soil 81 and ends up at humidity 78
+        assert_eq!(almanac.convert(81, "soil", "humidity"), Some(78));
+        assert_eq!(almanac.convert(81, "soil", "soil"), Some(81));
+        assert_eq!(almanac.convert(81, "humidity", "soil"), None);
+        assert_eq!(almanac.categories().count(), 8);
+    }
+
+    #[test]
+    fn reordered_sections() {
+        // the same maps, last ones first, plus one nothing needs
+        let mut input = INPUT[..2].to_vec();
+        input.extend(&INPUT[26..]);
+        input.extend(["", "water-to-rain map:", "1 2 3", ""]);
+        input.extend(&INPUT[2..25]);
+        let (seeds, almanac) = parse(&input).unwrap();
+        assert_eq!(solve1(&seeds, &almanac), 35);
+        assert_eq!(solve2(&seeds, &almanac), Ok(46));
+
+        let err = parse(&INPUT[..25]).unwrap_err();
+        assert_eq!(err.expected, "maps leading from seed to location");
+        let mut input = INPUT.to_vec();
+        input.extend(["", "seed-to-soil map:", "1 2 3"]);
+        assert_eq!(parse(&input).unwrap_err().line, 35);
+    }
+
+    #[test]
+    fn offsets_beyond_i64() {
+        let input = [
+            "seeds: 1 2",
+            "",
+            "seed-to-location map:",
+            "9223372036854775813 0 5",
+        ];
+        let (seeds, almanac) = parse(&input).unwrap();
+        assert_eq!(solve1(&seeds, &almanac), 9223372036854775814);
+        assert_eq!(solve2(&seeds, &almanac), Ok(9223372036854775814));
+
+        // offsets of almost ±2^64 that cancel out when composed
+        let input = [
+            "seeds: 0 1",
+            "",
+            "seed-to-soil map:",
+            "18446744073709551610 0 5",
+            "",
+            "soil-to-location map:",
+            "0 18446744073709551610 5",
+        ];
+        let (seeds, almanac) = parse(&input).unwrap();
+        let map = almanac.range_map("seed", "location").unwrap();
+        assert_eq!((map.get(3), map.get(18446744073709551612)), (3, 2));
+        assert_eq!(solve1(&seeds, &almanac), 0);
+    }
+
+    #[test]
+    fn errors() {
+        // fine for part 1, only part 2 needs the seeds to pair up
+        let mut input = INPUT.to_vec();
+        input[0] = "seeds: 79 14 55";
+        let (seeds, almanac) = parse(&input).unwrap();
+        assert_eq!(solve1(&seeds, &almanac), 43);
+        assert_eq!(solve2(&seeds, &almanac), Err(SeedRangeError::OddCount));
+        assert_eq!(
+            Day05::part2(&(seeds, almanac), &()),
+            Answer::Error("the last seed range has no length".to_owned())
+        );
+        input[0] = "seeds: 79 14 18446744073709551615 1";
+        let (seeds, almanac) = parse(&input).unwrap();
+        assert_eq!(
+            solve2(&seeds, &almanac),
+            Err(SeedRangeError::Overflow {
+                start: u64::MAX,
+                length: 1
+            })
+        );
+
+        let mut input = INPUT.to_vec();
+        input[3] = "52 18446744073709551610 48";
+        let err = parse(&input).unwrap_err();
+        assert_eq!((err.line, err.column), (4, 1));
+        assert_eq!(err.expected, "a mapping with ranges that stay below 2^64");
+    }
+
+    // non-overlapping inputs below 100, outputs anywhere below 150
+    fn random_mappings(rng: &mut Lcg) -> Vec<Mapping> {
+        let mut mappings = Vec::new();
+        let mut start = rng.below(10);
+        while start < 100 {
+            let len = 1 + rng.below(20);
+            let output = rng.below(150);
+            mappings.push(Mapping {
+                input_range: start..start + len,
+                output_range: output..output + len,
+            });
+            start += len + rng.below(10);
+        }
+        mappings
+    }
+
+    #[test]
+    fn range_map_matches_convert() {
+        let mut rng = Lcg::new(5);
+        for _ in 0..50 {
+            let layers = (0..4)
+                .map(|_| random_mappings(&mut rng))
+                .collect::<Vec<_>>();
+            let composed = layers.iter().fold(RangeMap::default(), |map, layer| {
+                map.compose(&RangeMap::from_mappings(layer))
+            });
+
+            let pointwise = |id| layers.iter().fold(id, |id, layer| convert(id, layer));
+            for id in 0..300 {
+                assert_eq!(composed.get(id), pointwise(id), "id {id}");
+            }
+
+            let start = rng.below(200);
+            let range = start..start + rng.below(50);
+            assert_eq!(composed.min_over(range.clone()), range.map(pointwise).min());
+        }
+    }
+
+    #[test]
+    fn range_map_inverse() {
+        // shuffle blocks of 0..100 around, which makes the map a bijection
+        let mut rng = Lcg::new(11);
+        for _ in 0..20 {
+            let mut blocks = Vec::new();
+            let mut start = 0;
+            while start < 100 {
+                let len = (1 + rng.below(15)).min(100 - start);
+                blocks.push(start..start + len);
+                start += len;
+            }
+            let mut order = blocks.clone();
+            for i in (1..order.len()).rev() {
+                order.swap(i, rng.below(i as u64 + 1) as usize);
+            }
+            let mut output = 0;
+            let mappings = order
+                .iter()
+                .map(|block| {
+                    let len = block.end - block.start;
+                    output += len;
+                    Mapping {
+                        input_range: block.clone(),
+                        output_range: output - len..output,
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            let map = RangeMap::from_mappings(&mappings);
+            let inverse = map.invert().unwrap();
+            for id in 0..150 {
+                assert_eq!(inverse.get(map.get(id)), id);
+            }
+            assert_eq!(map.compose(&inverse), RangeMap::default());
+        }
+
+        // 0..10 and 10..20 both end up on 10..20
+        let squashed = RangeMap::from_mappings(&[Mapping {
+            input_range: 0..10,
+            output_range: 10..20,
+        }]);
+        assert_eq!(squashed.invert(), None);
+    }
+
+    #[test]
+    fn location_to_seed() {
+        let (_, almanac) = parse(INPUT).unwrap();
+        let map = almanac.range_map("seed", "location").unwrap();
+        let back = almanac
+            .range_map("seed", "location")
+            .and_then(|m| m.invert())
+            .unwrap();
+        // seed 13 ends up at location 35
+        assert_eq!(map.get(13), 35);
+        assert_eq!(back.get(35), 13);
+        assert_eq!(map.min_over(82..83), Some(46));
+    }
+
+    #[test]
+    fn test2() {
+        let (seeds, almanac) = parse(INPUT).unwrap();
+        assert_eq!(solve2(&seeds, &almanac), Ok(46))
+    }
+}
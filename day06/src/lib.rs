@@ -0,0 +1,261 @@
+use std::fmt;
+
+use utils::{
+    parse::{self, Line, ParseError},
+    Answer, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Race {
+    pub time: u128,
+    pub distance: u128,
+}
+
+// the numbers after `label`, checking the label is there
+fn numbers<'a>(
+    line: Option<Line<'a>>,
+    label: &str,
+    num_lines: usize,
+) -> Result<(Line<'a>, Vec<&'a str>), ParseError> {
+    let line =
+        line.ok_or_else(|| ParseError::end_of_input(num_lines, format!("a {label:?} line")))?;
+    let Some(rest) = line.text.strip_prefix(label) else {
+        return Err(line.error(line.text, format!("{label:?}")));
+    };
+
+    Ok((line, rest.split_ascii_whitespace().collect()))
+}
+
+fn table<'a>(input: &[&'a str]) -> Result<[(Line<'a>, Vec<&'a str>); 2], ParseError> {
+    let mut lines = parse::lines(input);
+    let times = numbers(lines.next(), "Time:", input.len())?;
+    let distances = numbers(lines.next(), "Distance:", input.len())?;
+
+    Ok([times, distances])
+}
+
+// one race per column
+pub fn parse(input: &[&str]) -> Result<Vec<Race>, ParseError> {
+    let [(time_line, times), (distance_line, distances)] = table(input)?;
+    if times.len() != distances.len() {
+        let end = &distance_line.text[distance_line.text.len()..];
+        let at = distances.get(times.len()).copied().unwrap_or(end);
+        return Err(distance_line.error(at, format!("{} distances", times.len())));
+    }
+
+    times
+        .iter()
+        .zip(&distances)
+        .map(|(time, distance)| {
+            Ok(Race {
+                time: time_line.parse(time, "a time")?,
+                distance: distance_line.parse(distance, "a distance")?,
+            })
+        })
+        .collect()
+}
+
+// the same table with the spaces between digits ignored, which makes a single race
+pub fn parse_kerned(input: &[&str]) -> Result<Race, ParseError> {
+    let [times, distances] = table(input)?;
+    let joined = |(line, numbers): (Line, Vec<&str>), expected| {
+        for number in &numbers {
+            if let Some(idx) = number.find(|c: char| !c.is_ascii_digit()) {
+                return Err(line.error(&number[idx..], expected));
+            }
+        }
+        let first = numbers.first().copied().unwrap_or(line.text);
+        numbers
+            .concat()
+            .parse()
+            .map_err(|_| line.error(first, expected))
+    };
+
+    Ok(Race {
+        time: joined(times, "a time")?,
+        distance: joined(distances, "a distance")?,
+    })
+}
+
+// hold * (time - hold) > distance, without multiplying
+fn beats(race: Race, hold: u128) -> bool {
+    hold > 0 && hold < race.time && race.time - hold > race.distance / hold
+}
+
+// the first hold time that wins for a race whose peak does, by bisecting between
+// 0, which never wins, and the peak. Used when the closed form below would overflow
+fn search_first_win(race: Race) -> u128 {
+    let (mut lo, mut hi) = (0, race.time / 2);
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if beats(race, mid) {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    hi
+}
+
+// the first hold time that wins, for a race whose peak does. With time = 2h + r,
+// holding h - k travels h(h + r) - k(k + r), so it wins while k(k + r) < slack
+fn first_win(race: Race) -> u128 {
+    let (h, r) = (race.time / 2, race.time % 2);
+    // races from 2^65 on, where h(h + r) doesn't fit in a u128
+    let Some(peak) = h.checked_mul(h + r) else {
+        return search_first_win(race);
+    };
+    let slack = peak - race.distance;
+
+    // q² <= slack - 1, so k = q fits when r = 0. When r = 1, q - 1 fits as
+    // (q - 1)q < q² < slack and q + 1 doesn't as (q + 1)(q + 2) > (q + 1)² > slack - 1,
+    // so only q itself needs checking
+    let q = (slack - 1).isqrt();
+    let k = if q * (q + r) < slack { q } else { q - 1 };
+    h - k
+}
+
+pub fn ways_to_win(race: Race) -> u128 {
+    // the distance peaks at half the race time, if that doesn't win nothing does
+    if !beats(race, race.time / 2) {
+        return 0;
+    }
+
+    // winning hold times are symmetric around the peak
+    race.time - 2 * first_win(race) + 1
+}
+
+// the product of every race's ways to win, which can outgrow a u128
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProductOverflow;
+
+impl fmt::Display for ProductOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the product of the ways to win overflows a u128")
+    }
+}
+
+impl std::error::Error for ProductOverflow {}
+
+pub fn solve1(races: &[Race]) -> Result<u128, ProductOverflow> {
+    races
+        .iter()
+        .try_fold(1u128, |product, race| {
+            product.checked_mul(ways_to_win(*race))
+        })
+        .ok_or(ProductOverflow)
+}
+
+pub fn solve2(race: Race) -> u128 {
+    ways_to_win(race)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    // the table is read as separate races for part 1 and as one race for part 2
+    type Parsed<'a> = (Vec<Race>, Race);
+    type Params = ();
+    const PARAMS: () = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let lines = input.lines().collect::<Vec<_>>();
+        Ok((parse(&lines)?, parse_kerned(&lines)?))
+    }
+
+    fn part1((races, _): &Self::Parsed<'_>, _: &()) -> Answer {
+        solve1(races).into()
+    }
+
+    fn part2((_, race): &Self::Parsed<'_>, _: &()) -> Answer {
+        solve2(*race).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[&str] = &["Time:      7  15   30", "Distance:  9  40  200"];
+
+    fn brute_force(race: Race) -> u128 {
+        (0..=race.time)
+            .filter(|hold| hold * (race.time - hold) > race.distance)
+            .count() as u128
+    }
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve1(&parse(INPUT).unwrap()), Ok(288))
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve2(parse_kerned(INPUT).unwrap()), 71503)
+    }
+
+    #[test]
+    fn closed_form() {
+        for time in 0..60 {
+            for distance in 0..time * time / 4 + 3 {
+                let race = Race { time, distance };
+                assert_eq!(ways_to_win(race), brute_force(race), "{race:?}");
+                if ways_to_win(race) > 0 {
+                    assert_eq!(first_win(race), search_first_win(race), "{race:?}");
+                }
+            }
+        }
+
+        // holding 2^40 beats the distance by 1, on both sides of the closed form's limit
+        for time in [(1 << 65) - 1, (1 << 64) - 1, 1 << 65, (1 << 87) + 1] {
+            let race = Race {
+                time,
+                distance: (1 << 40) * (time - (1 << 40)) - 1,
+            };
+            let first = first_win(race);
+            assert!(beats(race, first) && !beats(race, first - 1));
+            assert_eq!(first, 1 << 40);
+            assert_eq!(ways_to_win(race), time - (1 << 41) + 1);
+        }
+
+        let race = Race {
+            time: u128::MAX,
+            distance: u128::MAX - 2,
+        };
+        assert_eq!(ways_to_win(race), u128::MAX - 1);
+        let race = Race {
+            time: u128::MAX,
+            distance: u128::MAX,
+        };
+        let first = first_win(race);
+        assert!(beats(race, first) && !beats(race, first - 1));
+    }
+
+    #[test]
+    fn product_overflow() {
+        let race = Race {
+            time: 1 << 70,
+            distance: 0,
+        };
+        assert_eq!(solve1(&[race]), Ok((1 << 70) - 1));
+        assert_eq!(solve1(&[race, race]), Err(ProductOverflow));
+        assert_eq!(
+            Day06::part1(&(vec![race, race], race), &()),
+            Answer::Error(ProductOverflow.to_string())
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = parse(&["Time: 7 15", "Distance: 9"]).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 12));
+        assert_eq!(err.expected, "2 distances");
+        let err = parse(&["Time: 7 x", "Distance: 9 40"]).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 9));
+        let err = parse_kerned(&["Time: 7 1x", "Distance: 9 40"]).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 10, "x"));
+        let err = parse(&["Time: 7"]).unwrap_err();
+        assert_eq!(err, ParseError::end_of_input(1, "a \"Distance:\" line"));
+    }
+}
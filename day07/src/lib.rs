@@ -0,0 +1,653 @@
+use std::{
+    cmp::Reverse,
+    fmt::{self, Write},
+};
+
+use utils::{
+    parse::{self, Line, ParseError},
+    Answer, Solution,
+};
+
+// everything the two parts disagree on: how cards rank and which ones are wild
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    // every valid card, weakest first
+    pub ranking: &'static str,
+    // cards that join whichever group makes the hand strongest
+    pub wildcards: &'static str,
+    pub hand_size: usize,
+}
+
+impl Rules {
+    pub const STANDARD: Self = Self {
+        ranking: "23456789TJQKA",
+        wildcards: "",
+        hand_size: 5,
+    };
+
+    // J is a joker, and the weakest card when breaking ties
+    pub const JOKERS: Self = Self {
+        ranking: "J23456789TQKA",
+        wildcards: "J",
+        hand_size: 5,
+    };
+
+    fn strength(&self, card: char) -> Option<usize> {
+        self.ranking.chars().position(|c| c == card)
+    }
+
+    fn is_wild(&self, card: char) -> bool {
+        self.wildcards.contains(card)
+    }
+
+    fn check(&self, line: &Line, cards: &str) -> Result<(), ParseError> {
+        if let Some((idx, c)) = cards
+            .char_indices()
+            .find(|(_, c)| self.strength(*c).is_none())
+        {
+            let card = &cards[idx..idx + c.len_utf8()];
+            return Err(line.error(card, format!("a card (one of {})", self.ranking)));
+        }
+        if cards.chars().count() != self.hand_size {
+            return Err(line.error(cards, format!("a hand of {} cards", self.hand_size)));
+        }
+
+        Ok(())
+    }
+
+    // the hand's groups of equal cards and what the wildcards made of them,
+    // `cards` has to be valid under these rules
+    pub fn explain<'a>(&self, cards: &'a str) -> Explanation<'a> {
+        let mut groups: Vec<(char, usize)> = Vec::new();
+        let mut wildcards = 0;
+        for card in cards.chars() {
+            if self.is_wild(card) {
+                wildcards += 1;
+            } else if let Some(group) = groups.iter_mut().find(|(c, _)| *c == card) {
+                group.1 += 1;
+            } else {
+                groups.push((card, 1));
+            }
+        }
+        groups.sort_by_key(|(card, count)| Reverse((*count, self.strength(*card))));
+
+        // adding every wildcard to the biggest group always gives the best kind,
+        // a hand of nothing but wildcards is one group on its own
+        let mut kind: Vec<usize> = groups.iter().map(|(_, count)| *count).collect();
+        let joined = match kind.first_mut() {
+            Some(largest) => {
+                *largest += wildcards;
+                (wildcards > 0).then_some(groups[0].0)
+            }
+            None => {
+                kind.extend((wildcards > 0).then_some(wildcards));
+                None
+            }
+        };
+
+        Explanation {
+            cards,
+            groups,
+            wildcards,
+            joined,
+            kind: Kind(kind),
+        }
+    }
+
+    pub fn hand(&self, cards: &str) -> Hand {
+        Hand {
+            kind: self.explain(cards).kind,
+            cards: cards
+                .chars()
+                .map(|c| self.strength(c).expect("card outside the ranking"))
+                .collect(),
+        }
+    }
+}
+
+// sizes of the groups of equal cards, largest first. Comparing these
+// lexicographically gives the usual order, from high card up to five of a kind
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Kind(pub Vec<usize>);
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.as_slice() {
+            [5] => write!(f, "five of a kind"),
+            [4, 1] => write!(f, "four of a kind"),
+            [3, 2] => write!(f, "full house"),
+            [3, 1, 1] => write!(f, "three of a kind"),
+            [2, 2, 1] => write!(f, "two pair"),
+            [2, 1, 1, 1] => write!(f, "one pair"),
+            [1, 1, 1, 1, 1] => write!(f, "high card"),
+            // no poker names for other hand sizes
+            counts => {
+                let counts: Vec<_> = counts.iter().map(|c| c.to_string()).collect();
+                write!(f, "groups of {}", counts.join("+"))
+            }
+        }
+    }
+}
+
+// the kind first, then the strength of each card in the order they were dealt
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Hand {
+    pub kind: Kind,
+    pub cards: Vec<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation<'a> {
+    pub cards: &'a str,
+    // the non-wild cards with how often each appears, largest group first
+    pub groups: Vec<(char, usize)>,
+    pub wildcards: usize,
+    // the card the wildcards counted as, None when there's nothing to join
+    pub joined: Option<char>,
+    pub kind: Kind,
+}
+
+impl fmt::Display for Explanation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.cards, self.kind)?;
+        let groups: Vec<_> = self
+            .groups
+            .iter()
+            .map(|(card, count)| format!("{card}x{count}"))
+            .collect();
+        match (self.wildcards, self.joined) {
+            (0, _) => write!(f, " ({})", groups.join(" ")),
+            (wildcards, Some(card)) => {
+                write!(f, " ({}, {wildcards} wild as {card})", groups.join(" "))
+            }
+            (wildcards, None) => write!(f, " ({wildcards} wild)"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HandBid<'a> {
+    pub cards: &'a str,
+    pub bid: u64,
+}
+
+// every hand has to be valid under each of `rules`
+pub fn parse<'a>(input: &[&'a str], rules: &[Rules]) -> Result<Vec<HandBid<'a>>, ParseError> {
+    parse::lines(input)
+        .map(|line| {
+            let (cards, bid) = line.split_once(line.text.trim(), " ")?;
+            for rules in rules {
+                rules.check(&line, cards)?;
+            }
+
+            Ok(HandBid {
+                cards,
+                bid: line.parse(bid, "a bid")?,
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RankedHand<'a> {
+    // position in the input
+    pub index: usize,
+    pub hand_bid: HandBid<'a>,
+    pub hand: Hand,
+    // 1 for the weakest hand
+    pub rank: u64,
+    // some other hand is equal to this one, so only input order decides which ranks higher
+    pub tied: bool,
+}
+
+// a hand's winnings or the total, which can outgrow a u64 with huge bids
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WinningsOverflow;
+
+impl fmt::Display for WinningsOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the winnings overflow a u64")
+    }
+}
+
+impl std::error::Error for WinningsOverflow {}
+
+impl RankedHand<'_> {
+    pub fn winnings(&self) -> Result<u64, WinningsOverflow> {
+        self.rank
+            .checked_mul(self.hand_bid.bid)
+            .ok_or(WinningsOverflow)
+    }
+}
+
+// the reports show an overflow in place of the winnings instead of failing
+fn shown(winnings: Result<u64, WinningsOverflow>) -> String {
+    winnings.map_or_else(|_| "overflow".to_owned(), |winnings| winnings.to_string())
+}
+
+fn change(before: Result<u64, WinningsOverflow>, after: Result<u64, WinningsOverflow>) -> String {
+    match (before, after) {
+        (Ok(before), Ok(after)) => format!("{:+}", i128::from(after) - i128::from(before)),
+        _ => "overflow".to_owned(),
+    }
+}
+
+// quoted when needed, a custom ranking could use commas as cards
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+// every hand with its rank and winnings, weakest first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report<'a> {
+    pub hands: Vec<RankedHand<'a>>,
+}
+
+impl<'a> Report<'a> {
+    pub fn new(hand_bids: &[HandBid<'a>], rules: &Rules) -> Self {
+        let mut hands: Vec<_> = hand_bids
+            .iter()
+            .enumerate()
+            .map(|(index, hand_bid)| RankedHand {
+                index,
+                hand_bid: *hand_bid,
+                hand: rules.hand(hand_bid.cards),
+                rank: 0,
+                tied: false,
+            })
+            .collect();
+        // stable, equal hands stay in input order
+        hands.sort_by(|a, b| a.hand.cmp(&b.hand));
+
+        let tied: Vec<bool> = (0..hands.len())
+            .map(|i| {
+                let equal = |j: usize| hands.get(j).is_some_and(|h| h.hand == hands[i].hand);
+                (i > 0 && equal(i - 1)) || equal(i + 1)
+            })
+            .collect();
+        for ((hand, rank), tied) in hands.iter_mut().zip(1..).zip(tied) {
+            hand.rank = rank;
+            hand.tied = tied;
+        }
+
+        Self { hands }
+    }
+
+    pub fn total(&self) -> Result<u64, WinningsOverflow> {
+        self.hands.iter().try_fold(0u64, |total, hand| {
+            total.checked_add(hand.winnings()?).ok_or(WinningsOverflow)
+        })
+    }
+
+    // the same hands, in input order
+    pub fn by_index(&self) -> Vec<&RankedHand<'a>> {
+        let mut hands: Vec<_> = self.hands.iter().collect();
+        hands.sort_by_key(|h| h.index);
+        hands
+    }
+
+    // tied hands are marked with a `=` after their rank
+    pub fn table(&self) -> String {
+        let mut out = format!(
+            "{:>6}  {:<8}{:<17}{:>8}{:>12}\n",
+            "rank", "hand", "kind", "bid", "winnings"
+        );
+        for hand in &self.hands {
+            writeln!(
+                out,
+                "{:>5}{}  {:<8}{:<17}{:>8}{:>12}",
+                hand.rank,
+                if hand.tied { "=" } else { " " },
+                hand.hand_bid.cards,
+                hand.hand.kind.to_string(),
+                hand.hand_bid.bid,
+                shown(hand.winnings())
+            )
+            .unwrap();
+        }
+        write!(out, "{:<6}{:>47}", "total", shown(self.total())).unwrap();
+
+        out
+    }
+
+    pub fn csv(&self) -> String {
+        let mut out = "rank,hand,kind,bid,winnings,tied\n".to_owned();
+        for hand in &self.hands {
+            writeln!(
+                out,
+                "{},{},{},{},{},{}",
+                hand.rank,
+                csv_field(hand.hand_bid.cards),
+                csv_field(&hand.hand.kind.to_string()),
+                hand.hand_bid.bid,
+                shown(hand.winnings()),
+                hand.tied
+            )
+            .unwrap();
+        }
+
+        out
+    }
+}
+
+// each hand in both reports, in input order. Both have to rank the same hands
+pub fn diff<'r, 'a>(
+    before: &'r Report<'a>,
+    after: &'r Report<'a>,
+) -> Vec<(&'r RankedHand<'a>, &'r RankedHand<'a>)> {
+    let (before, after) = (before.by_index(), after.by_index());
+    assert!(
+        before.len() == after.len()
+            && before
+                .iter()
+                .zip(&after)
+                .all(|(b, a)| b.hand_bid == a.hand_bid),
+        "reports over different hands"
+    );
+
+    before.into_iter().zip(after).collect()
+}
+
+// one row per hand in input order, with how far it moved and what that did to its winnings
+pub fn diff_table(before: &Report, after: &Report) -> String {
+    let mut out = format!(
+        "{:<8}{:>8}{:>8}{:>8}{:>8}  {:<17}{:<17}{:>12}\n",
+        "hand", "bid", "before", "after", "moved", "kind before", "kind after", "winnings"
+    );
+    for (b, a) in diff(before, after) {
+        writeln!(
+            out,
+            "{:<8}{:>8}{:>8}{:>8}{:>+8}  {:<17}{:<17}{:>12}",
+            b.hand_bid.cards,
+            b.hand_bid.bid,
+            b.rank,
+            a.rank,
+            a.rank as i64 - b.rank as i64,
+            b.hand.kind.to_string(),
+            a.hand.kind.to_string(),
+            change(b.winnings(), a.winnings())
+        )
+        .unwrap();
+    }
+    write!(
+        out,
+        "{:<8}{:>80}",
+        "total",
+        change(before.total(), after.total())
+    )
+    .unwrap();
+
+    out
+}
+
+pub fn winnings(hand_bids: &[HandBid], rules: &Rules) -> Result<u64, WinningsOverflow> {
+    Report::new(hand_bids, rules).total()
+}
+
+pub struct Params {
+    pub part1: Rules,
+    pub part2: Rules,
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    type Parsed<'a> = Vec<HandBid<'a>>;
+    type Params = Params;
+    const PARAMS: Params = Params {
+        part1: Rules::STANDARD,
+        part2: Rules::JOKERS,
+    };
+
+    fn parse(input: &str) -> Result<Vec<HandBid<'_>>, ParseError> {
+        let lines = input.lines().collect::<Vec<_>>();
+        parse(&lines, &[Self::PARAMS.part1, Self::PARAMS.part2])
+    }
+
+    fn part1(hand_bids: &Vec<HandBid>, params: &Params) -> Answer {
+        winnings(hand_bids, &params.part1).into()
+    }
+
+    fn part2(hand_bids: &Vec<HandBid>, params: &Params) -> Answer {
+        winnings(hand_bids, &params.part2).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[&str] = &[
+        "32T3K 765",
+        "T55J5 684",
+        "KK677 28",
+        "KTJJT 220",
+        "QQQJA 483",
+    ];
+
+    fn kind(rules: &Rules, cards: &str) -> String {
+        rules.explain(cards).kind.to_string()
+    }
+
+    // the best kind from trying every card in place of each wildcard
+    fn brute_force(rules: &Rules, cards: &str) -> Kind {
+        match cards.chars().position(|c| rules.is_wild(c)) {
+            None => {
+                Rules {
+                    wildcards: "",
+                    ..*rules
+                }
+                .explain(cards)
+                .kind
+            }
+            Some(idx) => rules
+                .ranking
+                .chars()
+                .filter(|c| !rules.is_wild(*c))
+                .map(|c| {
+                    let mut replaced = cards.to_owned();
+                    replaced.replace_range(idx..idx + 1, &c.to_string());
+                    brute_force(rules, &replaced)
+                })
+                .max()
+                .unwrap(),
+        }
+    }
+
+    #[test]
+    fn parse_errors() {
+        let rules = [Rules::STANDARD, Rules::JOKERS];
+        assert_eq!(parse(INPUT, &rules).unwrap().len(), 5);
+        let err = parse(&["32T3K 765", "T5XJ5 684"], &rules).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "X"));
+        let err = parse(&["32T3 765"], &rules).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 1, "32T3"));
+        let err = parse(&["32T3K -1"], &rules).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 7));
+
+        let small = Rules {
+            ranking: "abc",
+            wildcards: "",
+            hand_size: 3,
+        };
+        assert!(parse(&["cab 1"], &[small]).is_ok());
+        let err = parse(&["cab 1"], &[small, Rules::STANDARD]).unwrap_err();
+        assert_eq!(err.expected, "a card (one of 23456789TJQKA)");
+    }
+
+    #[test]
+    fn test1() {
+        let hand_bids = parse(INPUT, &[Rules::STANDARD]).unwrap();
+        assert_eq!(winnings(&hand_bids, &Rules::STANDARD), Ok(6440))
+    }
+
+    #[test]
+    fn test2() {
+        let hand_bids = parse(INPUT, &[Rules::JOKERS]).unwrap();
+        assert_eq!(winnings(&hand_bids, &Rules::JOKERS), Ok(5905))
+    }
+
+    #[test]
+    fn kinds() {
+        let rules = Rules::STANDARD;
+        assert_eq!(kind(&rules, "AAAAA"), "five of a kind");
+        assert_eq!(kind(&rules, "AA8AA"), "four of a kind");
+        assert_eq!(kind(&rules, "23332"), "full house");
+        assert_eq!(kind(&rules, "TTT98"), "three of a kind");
+        assert_eq!(kind(&rules, "23432"), "two pair");
+        assert_eq!(kind(&rules, "A23A4"), "one pair");
+        assert_eq!(kind(&rules, "23456"), "high card");
+
+        // a joker makes the hand stronger but loses ties against any other card
+        assert_eq!(kind(&Rules::JOKERS, "JKKK2"), "four of a kind");
+        assert!(Rules::JOKERS.hand("JKKK2") < Rules::JOKERS.hand("2KKKK"));
+        assert!(Rules::STANDARD.hand("JQQQ2") > Rules::STANDARD.hand("TQQQ2"));
+        assert_eq!(kind(&Rules::JOKERS, "JJJJJ"), "five of a kind");
+    }
+
+    #[test]
+    fn explanations() {
+        let explain = |rules: &Rules, cards| rules.explain(cards).to_string();
+        assert_eq!(
+            explain(&Rules::STANDARD, "KTJJT"),
+            "KTJJT: two pair (Jx2 Tx2 Kx1)"
+        );
+        assert_eq!(
+            explain(&Rules::JOKERS, "KTJJT"),
+            "KTJJT: four of a kind (Tx2 Kx1, 2 wild as T)"
+        );
+        assert_eq!(
+            explain(&Rules::JOKERS, "JJJJJ"),
+            "JJJJJ: five of a kind (5 wild)"
+        );
+
+        let six = Rules {
+            ranking: "*abc",
+            wildcards: "*",
+            hand_size: 6,
+        };
+        assert_eq!(
+            explain(&six, "ab*bca"),
+            "ab*bca: groups of 3+2+1 (bx2 ax2 cx1, 1 wild as b)"
+        );
+    }
+
+    #[test]
+    fn reports() {
+        let hand_bids = parse(INPUT, &[Rules::STANDARD]).unwrap();
+        let (standard, jokers) = (
+            Report::new(&hand_bids, &Rules::STANDARD),
+            Report::new(&hand_bids, &Rules::JOKERS),
+        );
+        assert_eq!(standard.total(), Ok(6440));
+        assert_eq!(
+            standard.csv(),
+            "rank,hand,kind,bid,winnings,tied\n\
+             1,32T3K,one pair,765,765,false\n\
+             2,KTJJT,two pair,220,440,false\n\
+             3,KK677,two pair,28,84,false\n\
+             4,T55J5,three of a kind,684,2736,false\n\
+             5,QQQJA,three of a kind,483,2415,false\n"
+        );
+        let table = standard.table();
+        assert!(table.contains("\n    2   KTJJT   two pair              220         440\n"));
+        assert!(table.ends_with("\ntotal                                            6440"));
+
+        let moved: Vec<_> = diff(&standard, &jokers)
+            .iter()
+            .map(|(b, a)| (b.hand_bid.cards, b.rank, a.rank))
+            .collect();
+        assert_eq!(
+            moved,
+            [
+                ("32T3K", 1, 1),
+                ("T55J5", 4, 3),
+                ("KK677", 3, 2),
+                ("KTJJT", 2, 5),
+                ("QQQJA", 5, 4)
+            ]
+        );
+        let diffs = diff_table(&standard, &jokers);
+        assert!(diffs.contains(
+            "\nKTJJT        220       2       5      +3  two pair         four of a kind           +660\n"
+        ));
+        assert!(diffs.ends_with(&format!("{:+}", 5905 - 6440)));
+    }
+
+    #[test]
+    fn ties() {
+        let hand_bids = parse(&["AAAAA 1", "23456 2", "AAAAA 3"], &[Rules::STANDARD]).unwrap();
+        let report = Report::new(&hand_bids, &Rules::STANDARD);
+        let ranks: Vec<_> = report
+            .hands
+            .iter()
+            .map(|h| (h.index, h.rank, h.tied))
+            .collect();
+        assert_eq!(ranks, [(1, 1, false), (0, 2, true), (2, 3, true)]);
+        assert!(report.table().contains("\n    2=  AAAAA"));
+
+        let custom = Rules {
+            ranking: "a,b",
+            wildcards: "",
+            hand_size: 2,
+        };
+        let hand_bids = parse(&["a, 4"], &[custom]).unwrap();
+        assert!(Report::new(&hand_bids, &custom)
+            .csv()
+            .ends_with("\n1,\"a,\",groups of 1+1,4,4,false\n"));
+    }
+
+    #[test]
+    fn overflow() {
+        let max = u64::MAX.to_string();
+        let lines = [format!("23456 {max}"), "AAAAA 1".to_owned()];
+        let lines: Vec<_> = lines.iter().map(String::as_str).collect();
+        let hand_bids = parse(&lines, &[Rules::STANDARD]).unwrap();
+        // each hand's winnings fit, their sum doesn't
+        let report = Report::new(&hand_bids, &Rules::STANDARD);
+        assert_eq!(report.hands[0].winnings(), Ok(u64::MAX));
+        assert_eq!(report.total(), Err(WinningsOverflow));
+        assert!(report.table().ends_with(" overflow"));
+        assert_eq!(
+            Day07::part1(&hand_bids, &Day07::PARAMS),
+            Answer::Error(WinningsOverflow.to_string())
+        );
+
+        let lines = ["23456 1", "AAAAA 9223372036854775808"];
+        let hand_bids = parse(&lines, &[Rules::STANDARD]).unwrap();
+        let report = Report::new(&hand_bids, &Rules::STANDARD);
+        assert_eq!(report.hands[1].winnings(), Err(WinningsOverflow));
+        assert!(report.csv().ends_with(",overflow,false\n"));
+        assert_eq!(winnings(&hand_bids, &Rules::JOKERS), Err(WinningsOverflow));
+    }
+
+    #[test]
+    fn wildcards_match_brute_force() {
+        // every hand over a small deck with two kinds of wildcard
+        let rules = Rules {
+            ranking: "J*234",
+            wildcards: "J*",
+            hand_size: 5,
+        };
+        let deck: Vec<char> = rules.ranking.chars().collect();
+        for mut n in 0..deck.len().pow(5) {
+            let cards: String = (0..5)
+                .map(|_| {
+                    let card = deck[n % deck.len()];
+                    n /= deck.len();
+                    card
+                })
+                .collect();
+            assert_eq!(
+                rules.explain(&cards).kind,
+                brute_force(&rules, &cards),
+                "{cards}"
+            );
+        }
+    }
+}
@@ -0,0 +1,500 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use utils::{
+    cycle, math,
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Dir {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+pub struct Node<'a> {
+    left: &'a str,
+    right: &'a str,
+}
+
+pub type Network<'a> = (Vec<Dir>, HashMap<&'a str, Node<'a>>);
+
+pub fn parse<'a>(input: &[&'a str]) -> Result<Network<'a>, ParseError> {
+    let mut lines = parse::lines(input);
+    let dir_line = lines
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(0, "a line of directions"))?;
+    let mut dirs = Vec::new();
+    for (idx, chr) in dir_line.text.char_indices() {
+        match chr {
+            'L' => dirs.push(Dir::Left),
+            'R' => dirs.push(Dir::Right),
+            _ => return Err(dir_line.char_error(idx, "L or R")),
+        }
+    }
+    // walks cycle through the directions, so there has to be one
+    if dirs.is_empty() {
+        return Err(dir_line.error(dir_line.text, "a line of directions"));
+    }
+
+    match lines.next() {
+        Some(line) if line.text.trim().is_empty() => {}
+        Some(line) => return Err(line.error(line.text, "an empty line after the directions")),
+        None => {
+            return Err(ParseError::end_of_input(
+                input.len(),
+                "an empty line after the directions",
+            ))
+        }
+    }
+
+    let parens: &[_] = &['(', ')'];
+    let mut nodes = HashMap::new();
+    let mut references = Vec::new();
+    for line in lines {
+        let (node_name, rest) = line.split_once(line.text, " = ")?;
+        let (left, right) = line.split_once(rest.trim_matches(parens), ", ")?;
+        nodes.insert(node_name, Node { left, right });
+        references.extend([(line, left), (line, right)]);
+    }
+
+    for (line, name) in references {
+        if !nodes.contains_key(name) {
+            return Err(line.error(name, "the name of a node"));
+        }
+    }
+
+    Ok((dirs, nodes))
+}
+
+// where a walk is: the current node and the index of the next direction to take
+pub type State<'a> = (&'a str, usize);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NavError {
+    UnknownNode(String),
+    // nothing matches the start predicate
+    NoStart,
+    // the walk from this node comes back to an earlier state without seeing a goal
+    Unreachable(String),
+    // every walk reaches a goal, but never all on the same step
+    NeverTogether,
+    // the walks do meet, but after more steps than a u64 counts
+    Overflow,
+}
+
+impl fmt::Display for NavError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NavError::UnknownNode(node) => write!(f, "there's no node called {node:?}"),
+            NavError::NoStart => write!(f, "no node to start from"),
+            NavError::Unreachable(node) => write!(f, "no goal can be reached from {node:?}"),
+            NavError::NeverTogether => write!(f, "the walks never all reach a goal at once"),
+            NavError::Overflow => write!(
+                f,
+                "the walks only meet after more steps than fit in 64 bits"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for NavError {}
+
+// the steps a walk is on a goal node, which repeat once it's back in an earlier state
+#[derive(Debug, Clone)]
+pub struct Walk<'a> {
+    // the node alone doesn't repeat, the node at a given point in the directions does
+    pub history: cycle::History<State<'a>>,
+    // goal steps before `start + period`, in increasing order
+    pub hits: Vec<u64>,
+}
+
+impl Walk<'_> {
+    // when the repeating part starts and how long it is
+    pub fn start(&self) -> u64 {
+        self.history.cycle.prefix as u64
+    }
+
+    pub fn period(&self) -> u64 {
+        self.history.cycle.period as u64
+    }
+
+    // only counts up to usize::MAX, which is all of u64 on 64 bit targets
+    pub fn hits_at(&self, steps: u64) -> bool {
+        usize::try_from(steps).is_ok_and(|steps| {
+            let steps = self.history.cycle.reduce(steps) as u64;
+            self.hits.binary_search(&steps).is_ok()
+        })
+    }
+
+    // the goal steps from the start of the loop onwards, each one comes round every period
+    fn loop_hits(&self) -> impl Iterator<Item = u64> + '_ {
+        self.hits
+            .iter()
+            .copied()
+            .filter(|steps| *steps >= self.start())
+    }
+
+    // Some(period) when the goal is on exactly the multiples of the period. That's what
+    // the puzzle input does, and what makes an lcm of the periods enough
+    pub fn clean_period(&self) -> Option<u64> {
+        let period = self.period();
+        let multiples = (1..)
+            .map(|k| k * period)
+            .take_while(|steps| *steps < self.start() + period);
+        multiples.eq(self.hits.iter().copied()).then_some(period)
+    }
+}
+
+// walks through the network, with goals and starting points picked by the caller
+pub struct Navigator<'n, 'a> {
+    dirs: &'n [Dir],
+    nodes: &'n HashMap<&'a str, Node<'a>>,
+}
+
+impl<'n, 'a> Navigator<'n, 'a> {
+    pub fn new(dirs: &'n [Dir], nodes: &'n HashMap<&'a str, Node<'a>>) -> Self {
+        Self { dirs, nodes }
+    }
+
+    fn step(&self, (node, dir_idx): State<'a>) -> State<'a> {
+        let next = match self.dirs[dir_idx] {
+            Dir::Left => self.nodes[node].left,
+            Dir::Right => self.nodes[node].right,
+        };
+        (next, (dir_idx + 1) % self.dirs.len())
+    }
+
+    fn start(&self, node: &str) -> Result<State<'a>, NavError> {
+        self.nodes
+            .get_key_value(node)
+            .map(|(name, _)| (*name, 0))
+            .ok_or_else(|| NavError::UnknownNode(node.to_owned()))
+    }
+
+    // every node from `start` up to and including the first goal, which can be `start` itself
+    pub fn trace(
+        &self,
+        start: &str,
+        is_goal: impl Fn(&str) -> bool,
+    ) -> Result<Vec<&'a str>, NavError> {
+        let mut state = self.start(start)?;
+        let mut seen = HashSet::new();
+        let mut trace = vec![state.0];
+        while !is_goal(state.0) {
+            if !seen.insert(state) {
+                return Err(NavError::Unreachable(start.to_owned()));
+            }
+            state = self.step(state);
+            trace.push(state.0);
+        }
+
+        Ok(trace)
+    }
+
+    pub fn steps(&self, start: &str, is_goal: impl Fn(&str) -> bool) -> Result<u64, NavError> {
+        Ok(self.trace(start, is_goal)?.len() as u64 - 1)
+    }
+
+    pub fn walk(&self, start: &str, is_goal: impl Fn(&str) -> bool) -> Result<Walk<'a>, NavError> {
+        let history = cycle::find_hashed(self.start(start)?, |state| self.step(*state));
+        let hits = (0..)
+            .zip(&history.states)
+            .filter(|(_, (node, _))| is_goal(node))
+            .map(|(steps, _)| steps)
+            .collect();
+
+        Ok(Walk { history, hits })
+    }
+
+    // first step count with a walk from every start node on a goal at the same time
+    pub fn arrival(
+        &self,
+        is_start: impl Fn(&str) -> bool,
+        is_goal: impl Fn(&str) -> bool,
+    ) -> Result<u64, NavError> {
+        let mut starts: Vec<_> = self.nodes.keys().filter(|node| is_start(node)).collect();
+        starts.sort();
+        if starts.is_empty() {
+            return Err(NavError::NoStart);
+        }
+        let walks = starts
+            .iter()
+            .map(|start| {
+                let walk = self.walk(start, &is_goal)?;
+                if walk.hits.is_empty() {
+                    return Err(NavError::Unreachable(start.to_string()));
+                }
+                Ok(walk)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // the quick way when every walk is a clean loop
+        let periods = walks
+            .iter()
+            .map(Walk::clean_period)
+            .collect::<Option<Vec<_>>>();
+        if let Some(steps) = periods.and_then(math::lcm_all) {
+            return Ok(steps);
+        }
+
+        simultaneous(&walks)
+    }
+}
+
+// the first step with every walk on a goal, for loops with any offsets
+fn simultaneous(walks: &[Walk]) -> Result<u64, NavError> {
+    let settled = walks.iter().map(Walk::start).max().unwrap_or(0);
+
+    // before every walk is looping there's nothing to do but check each step
+    if let Some(steps) = (0..settled).find(|steps| walks.iter().all(|w| w.hits_at(*steps))) {
+        return Ok(steps);
+    }
+
+    // after that every walk hits at fixed offsets modulo its period. Fold them in one
+    // walk at a time, keeping only the step counts every walk so far agrees on
+    let mut solutions = vec![(0, 1)];
+    for walk in walks {
+        let period = walk.period() as i64;
+        let mut next = Vec::new();
+        for &solution in &solutions {
+            for steps in walk.loop_hits() {
+                match math::crt_combine(solution, ((steps % walk.period()) as i64, period)) {
+                    Ok(combined) => next.push(combined),
+                    Err(math::CrtError::Contradiction) => {}
+                    Err(math::CrtError::Overflow) => return Err(NavError::Overflow),
+                }
+            }
+        }
+        // every solution now has the same modulus, so equal residues are duplicates
+        next.sort_unstable();
+        next.dedup();
+        if next.is_empty() {
+            return Err(NavError::NeverTogether);
+        }
+        solutions = next;
+    }
+
+    // lift each solution to the first one where every walk is looping
+    solutions
+        .into_iter()
+        .map(|(steps, lcm)| {
+            let (steps, lcm) = (steps as u64, lcm as u64);
+            settled
+                .saturating_sub(steps)
+                .div_ceil(lcm)
+                .checked_mul(lcm)
+                .and_then(|lift| lift.checked_add(steps))
+                .ok_or(NavError::Overflow)
+        })
+        .try_fold(u64::MAX, |min, steps| Ok(min.min(steps?)))
+}
+
+pub fn solve1(dirs: &[Dir], nodes: &HashMap<&str, Node>) -> Result<u64, NavError> {
+    Navigator::new(dirs, nodes).steps("AAA", |node| node == "ZZZ")
+}
+
+// first step count with every ghost on a Z node
+pub fn solve2(dirs: &[Dir], nodes: &HashMap<&str, Node>) -> Result<u64, NavError> {
+    Navigator::new(dirs, nodes).arrival(|node| node.ends_with('A'), |node| node.ends_with('Z'))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+    type Parsed<'a> = Network<'a>;
+    type Params = ();
+    const PARAMS: () = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(&input.lines().collect::<Vec<_>>())
+    }
+
+    fn part1((dirs, nodes): &Self::Parsed<'_>, _: &()) -> Answer {
+        solve1(dirs, nodes).into()
+    }
+
+    fn part2((dirs, nodes): &Self::Parsed<'_>, _: &()) -> Answer {
+        solve2(dirs, nodes).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT1: &[&str] = &[
+        "RL",
+        "",
+        "AAA = (BBB, CCC)",
+        "BBB = (DDD, EEE)",
+        "CCC = (ZZZ, GGG)",
+        "DDD = (DDD, DDD)",
+        "EEE = (EEE, EEE)",
+        "GGG = (GGG, GGG)",
+        "ZZZ = (ZZZ, ZZZ)",
+    ];
+
+    const INPUT2: &[&str] = &[
+        "LLR",
+        "",
+        "AAA = (BBB, BBB)",
+        "BBB = (AAA, ZZZ)",
+        "ZZZ = (ZZZ, ZZZ)",
+    ];
+
+    const INPUT3: &[&str] = &[
+        "LR",
+        "",
+        "11A = (11B, XXX)",
+        "11B = (XXX, 11Z)",
+        "11Z = (11B, XXX)",
+        "22A = (22B, XXX)",
+        "22B = (22C, 22C)",
+        "22C = (22Z, 22Z)",
+        "22Z = (22B, 22B)",
+        "XXX = (XXX, XXX)",
+    ];
+
+    #[test]
+    fn parse_errors() {
+        let err = parse(&["", "", "AAA = (AAA, AAA)"]).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(err.expected, "a line of directions");
+        let err = parse(&["LRX"]).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 3, "X"));
+
+        // without the blank line the first node would go missing
+        let err = parse(&["LR", "AAA = (BBB, BBB)", "BBB = (AAA, AAA)"]).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.text.as_str()),
+            (2, 1, "AAA = (BBB, BBB)")
+        );
+        assert_eq!(err.expected, "an empty line after the directions");
+        assert_eq!(parse(&["LR"]).unwrap_err().line, 2);
+    }
+
+    #[test]
+    fn test1() {
+        let (dirs, nodes) = parse(INPUT1).unwrap();
+        assert_eq!(solve1(&dirs, &nodes), Ok(2));
+        let (dirs, nodes) = parse(INPUT2).unwrap();
+        assert_eq!(solve1(&dirs, &nodes), Ok(6));
+    }
+
+    #[test]
+    fn test2() {
+        let (dirs, nodes) = parse(INPUT3).unwrap();
+        assert_eq!(solve2(&dirs, &nodes), Ok(6))
+    }
+
+    #[test]
+    fn navigation() {
+        let (dirs, nodes) = parse(INPUT1).unwrap();
+        let navigator = Navigator::new(&dirs, &nodes);
+        assert_eq!(
+            navigator.trace("AAA", |node| node == "ZZZ"),
+            Ok(vec!["AAA", "CCC", "ZZZ"])
+        );
+        assert_eq!(navigator.steps("BBB", |node| node == "BBB"), Ok(0));
+        // RL from AAA goes to CCC and then stays on ZZZ
+        assert_eq!(
+            navigator.steps("AAA", |node| node == "GGG"),
+            Err(NavError::Unreachable("AAA".to_owned()))
+        );
+        assert_eq!(
+            navigator.steps("QQQ", |_| true),
+            Err(NavError::UnknownNode("QQQ".to_owned()))
+        );
+        assert_eq!(
+            navigator.arrival(|_| false, |_| true),
+            Err(NavError::NoStart)
+        );
+        assert_eq!(
+            navigator.arrival(|node| node == "AAA" || node == "BBB", |node| node == "GGG"),
+            Err(NavError::Unreachable("AAA".to_owned()))
+        );
+
+        // the parts report a map with no way to the goal instead of panicking
+        let parsed = parse(&["L", "", "AAA = (BBB, BBB)", "BBB = (AAA, AAA)"]).unwrap();
+        assert_eq!(
+            Day08::part1(&parsed, &()),
+            Answer::Error("no goal can be reached from \"AAA\"".to_owned())
+        );
+        assert!(matches!(Day08::part2(&parsed, &()), Answer::Error(_)));
+    }
+
+    #[test]
+    fn clean_periods() {
+        let (dirs, nodes) = parse(INPUT3).unwrap();
+        let navigator = Navigator::new(&dirs, &nodes);
+        let is_goal = |node: &str| node.ends_with('Z');
+        // 11A is on 11Z every 2 steps
+        let walk = navigator.walk("11A", is_goal).unwrap();
+        assert_eq!(
+            (walk.start(), walk.period(), walk.clean_period()),
+            (1, 2, Some(2))
+        );
+        // 22A hits every 3 steps, but its states only repeat every 6
+        let walk = navigator.walk("22A", is_goal).unwrap();
+        assert_eq!(walk.hits, [3, 6]);
+        assert_eq!(walk.clean_period(), None);
+        assert!(walk.hits_at(9) && !walk.hits_at(10));
+    }
+
+    #[test]
+    fn offset_loops() {
+        // 11A reaches 11Z after 1, 3, 5, ... steps and 22A after 2, 5, 8, ...
+        let input = &[
+            "L",
+            "",
+            "11A = (11Z, XXX)",
+            "11Z = (11B, XXX)",
+            "11B = (11Z, XXX)",
+            "22A = (22B, XXX)",
+            "22B = (22Z, XXX)",
+            "22Z = (22C, XXX)",
+            "22C = (22D, XXX)",
+            "22D = (22Z, XXX)",
+            "XXX = (XXX, XXX)",
+        ];
+        let (dirs, nodes) = parse(input).unwrap();
+        assert_eq!(solve2(&dirs, &nodes), Ok(5));
+
+        // 11A only ever hits odd step counts, 22A only even ones
+        let input = &[
+            "L",
+            "",
+            "11A = (11Z, XXX)",
+            "11Z = (11B, XXX)",
+            "11B = (11Z, XXX)",
+            "22A = (22B, XXX)",
+            "22B = (22Z, XXX)",
+            "22Z = (22B, XXX)",
+            "XXX = (XXX, XXX)",
+        ];
+        let (dirs, nodes) = parse(input).unwrap();
+        assert_eq!(solve2(&dirs, &nodes), Err(NavError::NeverTogether));
+
+        // 11A hits twice in each loop of 3, after 1, 3, 4, 6, 7, ... steps
+        let input = &[
+            "L",
+            "",
+            "11A = (11Z, XXX)",
+            "11Z = (11B, XXX)",
+            "11B = (12Z, XXX)",
+            "12Z = (11Z, XXX)",
+            "22A = (22B, XXX)",
+            "22B = (22Z, XXX)",
+            "22Z = (22B, XXX)",
+            "XXX = (XXX, XXX)",
+        ];
+        let (dirs, nodes) = parse(input).unwrap();
+        assert_eq!(solve2(&dirs, &nodes), Ok(4));
+    }
+}
@@ -0,0 +1,237 @@
+use std::fmt;
+
+use utils::{
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryError {
+    Empty,
+    // the last row of differences still isn't zero, so the values don't pin down a polynomial
+    NoZeroRow,
+    // a difference doesn't fit in an i128
+    Overflow,
+}
+
+impl HistoryError {
+    // what a history line needed to be instead, for parse errors
+    pub fn expected(&self) -> &'static str {
+        match self {
+            HistoryError::Empty => "at least one value",
+            HistoryError::NoZeroRow => "values whose differences end in a row of zeros",
+            HistoryError::Overflow => "values with differences that fit in an i128",
+        }
+    }
+}
+
+impl fmt::Display for HistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HistoryError::Empty => write!(f, "the history has no values"),
+            HistoryError::NoZeroRow => {
+                write!(f, "the differences never reach a row of zeros")
+            }
+            HistoryError::Overflow => write!(f, "a difference overflows an i128"),
+        }
+    }
+}
+
+impl std::error::Error for HistoryError {}
+
+// the values of a polynomial at 0, 1, 2, ...
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct History {
+    pub values: Vec<i64>,
+    // Newton's forward differences, the first value of each row of differences down to the
+    // last non-zero one. The value at x is the sum of coefficient k times x choose k
+    pub coefficients: Vec<i128>,
+}
+
+impl History {
+    pub fn new(values: Vec<i64>) -> Result<Self, HistoryError> {
+        let mut row: Vec<i128> = values.iter().map(|v| *v as i128).collect();
+        let mut coefficients = Vec::new();
+        loop {
+            match row.first() {
+                None if coefficients.is_empty() => return Err(HistoryError::Empty),
+                None => return Err(HistoryError::NoZeroRow),
+                Some(_) if row.iter().all(|v| *v == 0) => break,
+                Some(first) => coefficients.push(*first),
+            }
+            row = row
+                .windows(2)
+                .map(|pair| pair[1].checked_sub(pair[0]).ok_or(HistoryError::Overflow))
+                .collect::<Result<_, _>>()?;
+        }
+
+        Ok(Self {
+            values,
+            coefficients,
+        })
+    }
+
+    // 0 for constants, all zeros included
+    pub fn degree(&self) -> usize {
+        self.coefficients.len().saturating_sub(1)
+    }
+
+    // the polynomial at any index, negative ones go back before the first value.
+    // None if anything on the way overflows
+    pub fn at(&self, index: i128) -> Option<i128> {
+        let mut value: i128 = 0;
+        // index choose k, generalised to negative indices
+        let mut choose: i128 = 1;
+        for (k, coefficient) in (0..).zip(&self.coefficients) {
+            if k > 0 {
+                // a product of k consecutive integers is always a multiple of k!
+                choose = choose.checked_mul(index.checked_sub(k - 1)?)? / k;
+            }
+            value = value.checked_add(coefficient.checked_mul(choose)?)?;
+        }
+
+        Some(value)
+    }
+
+    pub fn next(&self) -> Option<i128> {
+        self.at(self.values.len() as i128)
+    }
+
+    pub fn previous(&self) -> Option<i128> {
+        self.at(-1)
+    }
+}
+
+pub fn parse(input: &[&str]) -> Result<Vec<History>, ParseError> {
+    let mut histories = Vec::new();
+    for line in parse::lines(input) {
+        let values = line
+            .text
+            .split_ascii_whitespace()
+            .map(|s| line.parse(s, "a number"))
+            .collect::<Result<_, _>>()?;
+        let history = History::new(values).map_err(|e| line.error(line.text, e.expected()))?;
+        histories.push(history);
+    }
+
+    Ok(histories)
+}
+
+// valid histories can still extrapolate past what an i128 holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExtrapolationOverflow;
+
+impl fmt::Display for ExtrapolationOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the extrapolated values overflow an i128")
+    }
+}
+
+impl std::error::Error for ExtrapolationOverflow {}
+
+fn sum(values: impl IntoIterator<Item = Option<i128>>) -> Result<i128, ExtrapolationOverflow> {
+    values
+        .into_iter()
+        .try_fold(0i128, |sum, value| sum.checked_add(value?))
+        .ok_or(ExtrapolationOverflow)
+}
+
+pub fn solve1(histories: &[History]) -> Result<i128, ExtrapolationOverflow> {
+    sum(histories.iter().map(History::next))
+}
+
+pub fn solve2(histories: &[History]) -> Result<i128, ExtrapolationOverflow> {
+    sum(histories.iter().map(History::previous))
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+    type Parsed<'a> = Vec<History>;
+    type Params = ();
+    const PARAMS: () = ();
+
+    fn parse(input: &str) -> Result<Vec<History>, ParseError> {
+        parse(&input.lines().collect::<Vec<_>>())
+    }
+
+    fn part1(histories: &Vec<History>, _: &()) -> Answer {
+        solve1(histories).into()
+    }
+
+    fn part2(histories: &Vec<History>, _: &()) -> Answer {
+        solve2(histories).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[&str] = &["0 3 6 9 12 15", "1 3 6 10 15 21", "10 13 16 21 30 45"];
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve1(&parse(INPUT).unwrap()), Ok(114))
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve2(&parse(INPUT).unwrap()), Ok(2))
+    }
+
+    #[test]
+    fn extrapolation() {
+        let degrees: Vec<_> = parse(INPUT).unwrap().iter().map(History::degree).collect();
+        assert_eq!(degrees, [1, 2, 3]);
+
+        // 2x³ - 5x² + 7, sampled at 0..6
+        let poly = |x: i128| 2 * x * x * x - 5 * x * x + 7;
+        let history = History::new((0..6).map(|x| poly(x) as i64).collect()).unwrap();
+        assert_eq!(history.degree(), 3);
+        for x in -50..50 {
+            assert_eq!(history.at(x), Some(poly(x)), "{x}");
+        }
+        assert_eq!(history.at(1_000_000), Some(poly(1_000_000)));
+        assert_eq!(history.at(i128::MAX / 2), None);
+
+        let constant = History::new(vec![4, 4]).unwrap();
+        assert_eq!((constant.degree(), constant.at(-7)), (0, Some(4)));
+        assert_eq!(History::new(vec![0]).unwrap().at(3), Some(0));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(History::new(vec![]), Err(HistoryError::Empty));
+        assert_eq!(HistoryError::Empty.to_string(), "the history has no values");
+        // 1 2 4 8 -> 1 2 4 -> 1 2 -> 1
+        assert_eq!(History::new(vec![1, 2, 4, 8]), Err(HistoryError::NoZeroRow));
+        // differences of extreme values still fit, this is rejected for the non-zero last row
+        assert_eq!(
+            History::new(vec![i64::MIN, i64::MAX, i64::MIN, i64::MAX, i64::MIN]),
+            Err(HistoryError::NoZeroRow)
+        );
+
+        let err = parse(&["1 2 3", "1 2 4 8"]).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(
+            err.expected,
+            "values whose differences end in a row of zeros"
+        );
+        let err = parse(&["1 2 x"]).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 5));
+
+        // each extrapolates fine, their sum doesn't
+        let big = History {
+            values: vec![0],
+            coefficients: vec![i128::MAX],
+        };
+        let histories = vec![big.clone(), big];
+        assert_eq!(solve1(&histories), Err(ExtrapolationOverflow));
+        assert_eq!(
+            Day09::part1(&histories, &()),
+            Answer::Error("the extrapolated values overflow an i128".to_owned())
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use utils::{
+    parse::{self, ParseError},
+    Answer, Direction, Grid2D, Point, Solution,
+};
+
+pub mod maze;
+pub mod render;
+
+pub use maze::{Location, Maze};
+
+pub fn parse(input: &[&str]) -> Result<Maze, ParseError> {
+    let tiles = Grid2D::parse(input, "a pipe, ground or the start", |c| {
+        "|-LJ7F.S".contains(c).then_some(c)
+    })?;
+    // the tiles are all ascii, so x doubles as the byte offset
+    let tile_error =
+        |p: Point, expected| parse::Line::new(p.y, input[p.y]).char_error(p.x, expected);
+
+    let mut start = None;
+    for (point, tile) in tiles.iter() {
+        if *tile == 'S' {
+            if start.is_some() {
+                return Err(tile_error(point, "a single start tile"));
+            }
+            start = Some(point);
+        }
+    }
+    let start = start.ok_or_else(|| ParseError::end_of_input(input.len(), "a start tile"))?;
+
+    let mut grid = Grid2D::from_fn(tiles.width(), tiles.height(), |point| match tiles[point] {
+        '.' => Location::Ground,
+        // resolve its connections later
+        'S' => Location::Start,
+        pipe => Location::pipe(pipe, point).expect("checked while parsing"),
+    });
+
+    // the start connects to the neighbours that lead back into it
+    let mut connections = Direction::iter()
+        .filter_map(|dir| dir.step(start))
+        .filter(|adj| match grid.get(*adj) {
+            Some(Location::Pipe(a, b)) => *a == Some(start) || *b == Some(start),
+            _ => false,
+        });
+    let (Some(first), Some(second), None) =
+        (connections.next(), connections.next(), connections.next())
+    else {
+        return Err(tile_error(start, "a start connected to two pipes"));
+    };
+    grid[start] = Location::Pipe(Some(first), Some(second));
+
+    Maze::new(start, grid).ok_or_else(|| tile_error(start, "a start on a closed loop"))
+}
+
+pub fn solve1(maze: &Maze) -> usize {
+    maze.farthest()
+}
+
+pub fn solve2(maze: &Maze) -> usize {
+    maze.enclosed_by_area()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    type Parsed<'a> = Maze;
+    type Params = ();
+    const PARAMS: () = ();
+
+    fn parse(input: &str) -> Result<Maze, ParseError> {
+        parse(&input.lines().collect::<Vec<_>>())
+    }
+
+    fn part1(maze: &Maze, _: &()) -> Answer {
+        solve1(maze).into()
+    }
+
+    fn part2(maze: &Maze, _: &()) -> Answer {
+        solve2(maze).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use utils::input;
+
+    use super::*;
+
+    const INPUT1: &[&str] = &[".....", ".S-7.", ".|.|.", ".L-J.", "....."];
+    const INPUT2: &[&str] = &["..F7.", ".FJ|.", "SJ.L7", "|F--J", "LJ..."];
+    const INPUT3: &[&str] = &[
+        "...........",
+        ".S-------7.",
+        ".|F-----7|.",
+        ".||.....||.",
+        ".||.....||.",
+        ".|L-7.F-J|.",
+        ".|..|.|..|.",
+        ".L--J.L--J.",
+        "...........",
+    ];
+    const INPUT4: &[&str] = &[
+        ".F----7F7F7F7F-7....",
+        ".|F--7||||||||FJ....",
+        ".||.FJ||||||||L7....",
+        "FJL7L7LJLJ||LJ.L-7..",
+        "L--J.L7...LJS7F-7L7.",
+        "....F-J..F7FJ|L7L7L7",
+        "....L7.F7||L7|.L7L7|",
+        ".....|FJLJ|FJ|F7|.LJ",
+        "....FJL-7.||.||||...",
+        "....L---J.LJ.LJLJ...",
+    ];
+    const INPUT5: &[&str] = &[
+        "FF7FSF7F7F7F7F7F---7",
+        "L|LJ||||||||||||F--J",
+        "FL-7LJLJ||||||LJL-77",
+        "F--JF--7||LJLJ7F7FJ-",
+        "L---JF-JLJ.||-FJLJJ7",
+        "|F|F-JF---7F7-L7L|7|",
+        "|FFJF7L7F-JF7|JL---7",
+        "7-L-JL7||F7|L7F-7F7|",
+        "L.L7LFJ|||||FJL7||LJ",
+        "L7JLJL-JLJLJL--JLJ.L",
+    ];
+
+    #[test]
+    fn parse_errors() {
+        let err = parse(&[".....", ".S-7.", ".|x|."]).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 3, "x"));
+
+        let err = parse(&[".....", ".F-7."]).unwrap_err();
+        assert_eq!(err, ParseError::end_of_input(2, "a start tile"));
+
+        let err = parse(&[".....", ".S.7.", ".|.|."]).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "S"));
+    }
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve1(&parse(INPUT1).unwrap()), 4);
+        assert_eq!(solve1(&parse(INPUT2).unwrap()), 8);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve2(&parse(INPUT3).unwrap()), 4);
+        assert_eq!(solve2(&parse(INPUT4).unwrap()), 8);
+        assert_eq!(solve2(&parse(INPUT5).unwrap()), 10);
+    }
+
+    #[test]
+    fn main_loop() {
+        let maze = parse(INPUT1).unwrap();
+        let p = |x, y| Point { x, y };
+        assert_eq!(
+            maze.main_loop,
+            [
+                p(1, 1),
+                p(2, 1),
+                p(3, 1),
+                p(3, 2),
+                p(3, 3),
+                p(2, 3),
+                p(1, 3),
+                p(1, 2)
+            ]
+        );
+        assert_eq!(maze.start_shape(), 'F');
+        assert_eq!(parse(INPUT2).unwrap().start_shape(), 'F');
+        assert_eq!(parse(INPUT5).unwrap().start_shape(), '7');
+
+        // the pipes out of S meet a dead end instead of coming back
+        let err = parse(&[".....", ".S-7.", ".|.|.", ".L-..", "....."]).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.expected, "a start on a closed loop");
+    }
+
+    #[test]
+    fn enclosed_areas_agree() {
+        for (input, expected) in [(INPUT1, 1), (INPUT3, 4), (INPUT4, 8), (INPUT5, 10)] {
+            let maze = parse(input).unwrap();
+            assert_eq!(maze.enclosed_by_flood_fill(), expected);
+            assert_eq!(maze.enclosed_by_area(), expected);
+            assert_eq!(
+                maze.inside_tiles().iter().filter(|(_, t)| **t).count(),
+                expected
+            );
+        }
+
+        let input = input::load(10, &input::Source::Conventional).unwrap();
+        let maze = Day10::parse(&input).unwrap();
+        assert_eq!(maze.enclosed_by_flood_fill(), 325);
+        assert_eq!(maze.enclosed_by_area(), 325);
+        assert_eq!(maze.inside_tiles().iter().filter(|(_, t)| **t).count(), 325);
+    }
+}
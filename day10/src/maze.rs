@@ -0,0 +1,187 @@
+use utils::{Direction, Grid2D, IPoint, Point};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Ground,
+    Start,
+    // the two tiles it leads to, None where that would be off the top or left edge
+    Pipe(Option<Point>, Option<Point>),
+}
+
+// every pipe with the two directions it connects
+pub const PIPES: [(char, [Direction; 2]); 6] = [
+    ('|', [Direction::Up, Direction::Down]),
+    ('-', [Direction::Left, Direction::Right]),
+    ('L', [Direction::Up, Direction::Right]),
+    ('J', [Direction::Left, Direction::Up]),
+    ('7', [Direction::Left, Direction::Down]),
+    ('F', [Direction::Down, Direction::Right]),
+];
+
+impl Location {
+    pub fn pipe(shape: char, point: Point) -> Option<Self> {
+        let (_, [a, b]) = PIPES.iter().find(|(c, _)| *c == shape)?;
+        Some(Location::Pipe(a.step(point), b.step(point)))
+    }
+
+    // the character a pipe at `point` is drawn with, which works for a resolved start too
+    pub fn shape(&self, point: Point) -> Option<char> {
+        let Location::Pipe(Some(a), Some(b)) = *self else {
+            return None;
+        };
+        let direction = |to| Direction::iter().find(|d| d.step(point) == Some(to));
+        let (a, b) = (direction(a)?, direction(b)?);
+        PIPES
+            .iter()
+            .find(|(_, dirs)| dirs.contains(&a) && dirs.contains(&b) && a != b)
+            .map(|(c, _)| *c)
+    }
+}
+
+// a grid of pipes with a single loop through the start
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Maze {
+    pub start: Point,
+    // the start is already replaced by the pipe it stands in for
+    pub grid: Grid2D<Location>,
+    // every tile of the loop in order, beginning with the start
+    pub main_loop: Vec<Point>,
+}
+
+impl Maze {
+    // None when the pipes from the start don't lead back to it
+    pub fn new(start: Point, grid: Grid2D<Location>) -> Option<Self> {
+        let Location::Pipe(Some(first), Some(_)) = grid[start] else {
+            return None;
+        };
+
+        let mut main_loop = vec![start];
+        let (mut prev, mut cur) = (start, first);
+        while cur != start {
+            // each step has to be into a pipe that leads back the same way
+            let Some(&Location::Pipe(Some(a), Some(b))) = grid.get(cur) else {
+                return None;
+            };
+            let next = match (a == prev, b == prev) {
+                (true, _) => b,
+                (_, true) => a,
+                _ => return None,
+            };
+            main_loop.push(cur);
+            (prev, cur) = (cur, next);
+        }
+        if !matches!(grid[start], Location::Pipe(a, b) if a == Some(prev) || b == Some(prev)) {
+            return None;
+        }
+
+        Some(Self {
+            start,
+            grid,
+            main_loop,
+        })
+    }
+
+    pub fn start_shape(&self) -> char {
+        self.grid[self.start]
+            .shape(self.start)
+            .expect("the start is resolved to a pipe when parsing")
+    }
+
+    pub fn on_loop(&self) -> Grid2D<bool> {
+        let mut on_loop = Grid2D::new(self.grid.width(), self.grid.height(), false);
+        for point in &self.main_loop {
+            on_loop[*point] = true;
+        }
+        on_loop
+    }
+
+    // which tiles the loop encloses, by scanning each row and flipping between outside and
+    // inside at every loop tile with a pipe going up
+    pub fn inside_tiles(&self) -> Grid2D<bool> {
+        let on_loop = self.on_loop();
+        let mut inside = Grid2D::new(self.grid.width(), self.grid.height(), false);
+        for y in 0..self.grid.height() {
+            let mut crossed = false;
+            for x in 0..self.grid.width() {
+                let point = Point { x, y };
+                if !on_loop[point] {
+                    inside[point] = crossed;
+                } else if let Location::Pipe(a, b) = self.grid[point] {
+                    let up = Direction::Up.step(point);
+                    if up.is_some() && (a == up || b == up) {
+                        crossed = !crossed;
+                    }
+                }
+            }
+        }
+        inside
+    }
+
+    // steps along the loop to the tile furthest from the start
+    pub fn farthest(&self) -> usize {
+        self.main_loop.len() / 2
+    }
+
+    // doubles the grid so the gaps between pipes become tiles, then floods in from the edge
+    pub fn enclosed_by_flood_fill(&self) -> usize {
+        // tile is either pipe or not, with a ring of ground tiles around the full perimeter
+        let mut doubled_grid =
+            Grid2D::new(self.grid.width() * 2 + 2, self.grid.height() * 2 + 2, false);
+        for &Point { x, y } in &self.main_loop {
+            let Location::Pipe(Some(conn_a), Some(conn_b)) = self.grid[Point { x, y }] else {
+                unreachable!("the loop only goes through pipes");
+            };
+            doubled_grid[Point {
+                x: x * 2 + 1,
+                y: y * 2 + 1,
+            }] = true;
+
+            // halfway to each connection, i.e. (x * 2 + 1, y * 2 + 1) + conn - (x, y)
+            for conn in [conn_a, conn_b] {
+                doubled_grid[Point {
+                    x: x + conn.x + 1,
+                    y: y + conn.y + 1,
+                }] = true;
+            }
+        }
+
+        // flood fill outside tiles, bounded by pipes
+        let mut outside_tiles = Grid2D::new(doubled_grid.width(), doubled_grid.height(), false);
+        let mut seeds = vec![Point { x: 0, y: 0 }];
+        while let Some(seed) = seeds.pop() {
+            for adj in doubled_grid.adjacents(seed) {
+                let adj = Point { x: adj.x, y: adj.y };
+                if !doubled_grid[adj] && !outside_tiles[adj] {
+                    outside_tiles[adj] = true;
+                    seeds.push(adj);
+                }
+            }
+        }
+
+        // only the odd coordinates are tiles of the original grid
+        (1..outside_tiles.height())
+            .step_by(2)
+            .flat_map(|y| {
+                (1..outside_tiles.width())
+                    .step_by(2)
+                    .map(move |x| Point { x, y })
+            })
+            .filter(|point| !doubled_grid[*point] && !outside_tiles[*point])
+            .count()
+    }
+
+    // the loop's area from the shoelace formula, then Pick's theorem
+    // (area = inside + boundary / 2 - 1) gives the tiles inside
+    pub fn enclosed_by_area(&self) -> usize {
+        let corners: Vec<IPoint> = self.main_loop.iter().map(|p| IPoint::from(*p)).collect();
+        let twice_area = corners
+            .iter()
+            .zip(corners.iter().cycle().skip(1))
+            .map(|(a, b)| a.x * b.y - b.x * a.y)
+            .sum::<i64>()
+            .abs();
+        let boundary = corners.len() as i64;
+
+        ((twice_area - boundary) / 2 + 1) as usize
+    }
+}
@@ -0,0 +1,93 @@
+use utils::Point;
+
+use crate::Maze;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    // characters only, for files and terminals without colours
+    Plain,
+    // ANSI colours on top of the same characters
+    Color,
+}
+
+const RESET: &str = "\x1b[0m";
+const START_COLOR: &str = "\x1b[1;33m";
+const INSIDE_COLOR: &str = "\x1b[32m";
+const OUTSIDE_COLOR: &str = "\x1b[90m";
+
+// the box-drawing glyph for a pipe, heavy ones pick out the start
+fn glyph(pipe: char, heavy: bool) -> char {
+    let glyphs = if heavy {
+        "┃━┗┛┓┏"
+    } else {
+        "│─└┘┐┌"
+    };
+    let idx = "|-LJ7F".find(pipe).expect("not a pipe");
+    glyphs.chars().nth(idx).unwrap()
+}
+
+// the main loop in box-drawing characters, with every other tile shown as inside (█)
+// or outside (·) of it. Pipes that aren't part of the loop are drawn like ground
+pub fn render(maze: &Maze, style: Style) -> String {
+    let on_loop = maze.on_loop();
+    let inside = maze.inside_tiles();
+    let paint = |out: &mut String, color: &str, chr: char| match style {
+        Style::Plain => out.push(chr),
+        Style::Color => {
+            out.push_str(color);
+            out.push(chr);
+            out.push_str(RESET);
+        }
+    };
+
+    let mut out = String::new();
+    for y in 0..maze.grid.height() {
+        if y > 0 {
+            out.push('\n');
+        }
+        for x in 0..maze.grid.width() {
+            let point = Point { x, y };
+            if on_loop[point] {
+                let pipe = maze.grid[point]
+                    .shape(point)
+                    .expect("the loop only goes through pipes");
+                if point == maze.start {
+                    paint(&mut out, START_COLOR, glyph(pipe, true));
+                } else {
+                    out.push(glyph(pipe, false));
+                }
+            } else if inside[point] {
+                paint(&mut out, INSIDE_COLOR, '█');
+            } else {
+                paint(&mut out, OUTSIDE_COLOR, '·');
+            }
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn plain() {
+        // the junk pipes around the loop are hidden, the one in the middle is enclosed
+        let maze = parse(&["7-F7-", ".FJ|7", "SJLL7", "|F--J", "LJ.LJ"]).unwrap();
+        assert_eq!(
+            render(&maze, Style::Plain),
+            "··┌┐·\n·┌┘│·\n┏┘█└┐\n│┌──┘\n└┘···"
+        );
+    }
+
+    #[test]
+    fn colors() {
+        let maze = parse(&[".....", ".S-7.", ".|.|.", ".L-J.", "....."]).unwrap();
+        let rendered = render(&maze, Style::Color);
+        assert!(rendered.starts_with(&format!("{OUTSIDE_COLOR}·{RESET}")));
+        assert!(rendered.contains(&format!("{START_COLOR}┏{RESET}─┐")));
+        assert!(rendered.contains(&format!("│{INSIDE_COLOR}█{RESET}│")));
+    }
+}
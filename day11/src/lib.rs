@@ -0,0 +1,236 @@
+use std::fmt;
+
+use utils::{
+    parse::{self, ParseError},
+    Answer, IPoint, Solution,
+};
+
+// how many times wider each empty column and taller each empty row gets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Expansion {
+    pub x: u64,
+    pub y: u64,
+}
+
+impl Expansion {
+    pub const fn uniform(factor: u64) -> Self {
+        Self {
+            x: factor,
+            y: factor,
+        }
+    }
+}
+
+// big enough factors push galaxies, or the distances between them, past an i64
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExpansionOverflow;
+
+impl fmt::Display for ExpansionOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the expanded universe doesn't fit in an i64")
+    }
+}
+
+impl std::error::Error for ExpansionOverflow {}
+
+// moves every coordinate along by (factor - 1) for each empty line before it
+fn expand_axis(coords: &[i64], factor: u64) -> Result<Vec<i64>, ExpansionOverflow> {
+    let growth = i64::try_from(factor).map_err(|_| ExpansionOverflow)? - 1;
+    let mut occupied = coords.to_vec();
+    occupied.sort_unstable();
+    occupied.dedup();
+
+    coords
+        .iter()
+        .map(|&c| {
+            let occupied_before = occupied.partition_point(|o| *o < c) as i64;
+            (c - occupied_before)
+                .checked_mul(growth)
+                .and_then(|shift| c.checked_add(shift))
+                .ok_or(ExpansionOverflow)
+        })
+        .collect()
+}
+
+// sum of |a - b| over every pair, from the sorted values and their running total
+fn pairwise_sum(mut values: Vec<i64>) -> Result<i64, ExpansionOverflow> {
+    values.sort_unstable();
+    let mut before = 0i64;
+    (0..)
+        .zip(values)
+        .try_fold(0i64, |total, (i, v)| {
+            let sum = v.checked_mul(i)?.checked_sub(before)?;
+            before = before.checked_add(v)?;
+            total.checked_add(sum)
+        })
+        .ok_or(ExpansionOverflow)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Universe {
+    // in reading order of the image
+    pub galaxies: Vec<IPoint>,
+}
+
+impl Universe {
+    // the same galaxies in the same order, after expanding
+    pub fn expanded(&self, expansion: Expansion) -> Result<Universe, ExpansionOverflow> {
+        let xs: Vec<_> = self.galaxies.iter().map(|g| g.x).collect();
+        let ys: Vec<_> = self.galaxies.iter().map(|g| g.y).collect();
+        let galaxies = expand_axis(&xs, expansion.x)?
+            .into_iter()
+            .zip(expand_axis(&ys, expansion.y)?)
+            .map(|(x, y)| IPoint::new(x, y))
+            .collect();
+        Ok(Universe { galaxies })
+    }
+
+    // steps between two galaxies, by their index in reading order
+    pub fn distance(&self, a: usize, b: usize) -> i64 {
+        self.galaxies[a].manhattan(self.galaxies[b])
+    }
+
+    // distance summed over every pair of galaxies, the axes can be done separately
+    pub fn total_distance(&self) -> Result<i64, ExpansionOverflow> {
+        pairwise_sum(self.galaxies.iter().map(|g| g.x).collect())?
+            .checked_add(pairwise_sum(self.galaxies.iter().map(|g| g.y).collect())?)
+            .ok_or(ExpansionOverflow)
+    }
+}
+
+pub fn parse(input: &[&str]) -> Result<Universe, ParseError> {
+    let mut galaxies = Vec::new();
+    for line in parse::lines(input) {
+        for (x, chr) in line.text.char_indices() {
+            match chr {
+                '#' => galaxies.push(IPoint::new(x as i64, line.index as i64)),
+                '.' => {}
+                _ => return Err(line.char_error(x, "a galaxy or empty space")),
+            }
+        }
+    }
+
+    Ok(Universe { galaxies })
+}
+
+pub fn solve(universe: &Universe, expansion: Expansion) -> Result<i64, ExpansionOverflow> {
+    universe.expanded(expansion)?.total_distance()
+}
+
+pub struct Params {
+    pub part1: Expansion,
+    pub part2: Expansion,
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    type Parsed<'a> = Universe;
+    type Params = Params;
+    const PARAMS: Params = Params {
+        part1: Expansion::uniform(2),
+        part2: Expansion::uniform(1000000),
+    };
+
+    fn parse(input: &str) -> Result<Universe, ParseError> {
+        parse(&input.lines().collect::<Vec<_>>())
+    }
+
+    fn part1(universe: &Universe, params: &Params) -> Answer {
+        solve(universe, params.part1).into()
+    }
+
+    fn part2(universe: &Universe, params: &Params) -> Answer {
+        solve(universe, params.part2).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[&str] = &[
+        "...#......",
+        ".......#..",
+        "#.........",
+        "..........",
+        "......#...",
+        ".#........",
+        ".........#",
+        "..........",
+        ".......#..",
+        "#...#.....",
+    ];
+
+    #[test]
+    fn test1() {
+        let universe = parse(INPUT).unwrap();
+        assert_eq!(solve(&universe, Expansion::uniform(2)), Ok(374))
+    }
+
+    #[test]
+    fn test2() {
+        let universe = parse(INPUT).unwrap();
+        assert_eq!(solve(&universe, Expansion::uniform(10)), Ok(1030));
+        assert_eq!(solve(&universe, Expansion::uniform(100)), Ok(8410));
+    }
+
+    #[test]
+    fn pairs() {
+        let universe = parse(INPUT).unwrap();
+        let doubled = universe.expanded(Expansion::uniform(2)).unwrap();
+        // the puzzle numbers galaxies from 1
+        for (a, b, steps) in [(5, 9, 9), (1, 7, 15), (3, 6, 17), (8, 9, 5)] {
+            assert_eq!(doubled.distance(a - 1, b - 1), steps);
+        }
+        assert_eq!(doubled.galaxies[0], IPoint::new(4, 0));
+
+        // the fast sum against every pair, with the axes expanding differently
+        for expansion in [
+            Expansion { x: 1, y: 1 },
+            Expansion { x: 3, y: 1 },
+            Expansion { x: 0, y: 7 },
+        ] {
+            let expanded = universe.expanded(expansion).unwrap();
+            let count = expanded.galaxies.len();
+            let slow: i64 = (0..count)
+                .flat_map(|a| (a + 1..count).map(move |b| (a, b)))
+                .map(|(a, b)| expanded.distance(a, b))
+                .sum();
+            assert_eq!(expanded.total_distance(), Ok(slow), "{expansion:?}");
+        }
+        let wide = universe.expanded(Expansion { x: 3, y: 1 }).unwrap();
+        assert_eq!(wide.galaxies[2], IPoint::new(0, 2));
+        assert_eq!(wide.galaxies[1], IPoint::new(11, 1));
+    }
+
+    #[test]
+    fn overflow() {
+        let universe = parse(INPUT).unwrap();
+        for factor in [u64::MAX, 1 << 62] {
+            let expansion = Expansion::uniform(factor);
+            assert_eq!(universe.expanded(expansion), Err(ExpansionOverflow));
+        }
+        // the galaxies still fit, but not the sum of the distances between them
+        let expansion = Expansion::uniform(1 << 59);
+        assert!(universe.expanded(expansion).is_ok());
+        assert_eq!(solve(&universe, expansion), Err(ExpansionOverflow));
+        assert_eq!(
+            Day11::part2(
+                &universe,
+                &Params {
+                    part1: expansion,
+                    part2: expansion
+                }
+            ),
+            Answer::Error(ExpansionOverflow.to_string())
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = parse(&["..#", ".x."]).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+    }
+}
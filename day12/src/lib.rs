@@ -0,0 +1,394 @@
+use std::fmt;
+
+use utils::{
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Status {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl Status {
+    pub fn symbol(self) -> char {
+        match self {
+            Status::Operational => '.',
+            Status::Damaged => '#',
+            Status::Unknown => '?',
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub springs: Vec<Status>,
+    // sizes of the runs of damaged springs, in order
+    pub groups: Vec<usize>,
+}
+
+impl Record {
+    // `factor` copies of the springs joined by unknowns, and as many copies of the groups
+    pub fn unfold(&self, factor: usize) -> Self {
+        let mut springs = Vec::new();
+        for copy in 0..factor {
+            if copy > 0 {
+                springs.push(Status::Unknown);
+            }
+            springs.extend(&self.springs);
+        }
+
+        Self {
+            springs,
+            groups: self.groups.repeat(factor),
+        }
+    }
+
+    // whether a fully known row of springs fits this record
+    pub fn matches(&self, springs: &[Status]) -> bool {
+        let agrees = springs.len() == self.springs.len()
+            && springs
+                .iter()
+                .zip(&self.springs)
+                .all(|(s, known)| *known == Status::Unknown || s == known);
+        let groups = springs
+            .split(|s| *s != Status::Damaged)
+            .map(|run| run.len())
+            .filter(|len| *len > 0);
+
+        agrees && groups.eq(self.groups.iter().copied())
+    }
+}
+
+pub fn parse(input: &[&str]) -> Result<Vec<Record>, ParseError> {
+    let mut records = Vec::new();
+
+    for line in parse::lines(input) {
+        let (springs_str, groups_str) = line.split_once(line.text, " ")?;
+        let springs = springs_str
+            .char_indices()
+            .map(|(idx, c)| match c {
+                '.' => Ok(Status::Operational),
+                '#' => Ok(Status::Damaged),
+                '?' => Ok(Status::Unknown),
+                _ => Err(line.char_error(idx, "a spring status (one of .#?)")),
+            })
+            .collect::<Result<_, _>>()?;
+
+        let groups = groups_str
+            .split(',')
+            .map(|c| match line.parse(c, "a group size")? {
+                0 => Err(line.error(c, "a group size above 0")),
+                len => Ok(len),
+            })
+            .collect::<Result<_, _>>()?;
+
+        records.push(Record { springs, groups });
+    }
+
+    Ok(records)
+}
+
+// long enough records have more arrangements than a u128 holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CountOverflow;
+
+impl fmt::Display for CountOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the arrangement count overflows a u128")
+    }
+}
+
+impl std::error::Error for CountOverflow {}
+
+// every way to fill in a record's unknown springs
+pub struct Arrangements<'r> {
+    record: &'r Record,
+    // ways[i][j]: arrangements of the springs from i on that make the groups from j on.
+    // Has a row past the end for a group that finishes on the last spring
+    ways: Vec<Vec<u128>>,
+}
+
+impl<'r> Arrangements<'r> {
+    pub fn new(record: &'r Record) -> Result<Self, CountOverflow> {
+        let (springs, groups) = (&record.springs, &record.groups);
+        let (n, m) = (springs.len(), groups.len());
+
+        // operational[i]: how many of the first i springs are known to be operational
+        let mut operational = vec![0; n + 1];
+        for (i, spring) in springs.iter().enumerate() {
+            operational[i + 1] = operational[i] + usize::from(*spring == Status::Operational);
+        }
+        // a group of `len` can start at i if none of it is operational and it isn't
+        // followed straight away by another damaged spring
+        let fits = |i: usize, len: usize| {
+            i + len <= n
+                && operational[i + len] == operational[i]
+                && springs.get(i + len) != Some(&Status::Damaged)
+        };
+
+        let mut ways = vec![vec![0; m + 1]; n + 2];
+        ways[n][m] = 1;
+        ways[n + 1][m] = 1;
+        for i in (0..n).rev() {
+            for j in (0..=m).rev() {
+                let mut count: u128 = 0;
+                if springs[i] != Status::Damaged {
+                    count = ways[i + 1][j];
+                }
+                if springs[i] != Status::Operational && j < m && fits(i, groups[j]) {
+                    count = count
+                        .checked_add(ways[i + groups[j] + 1][j + 1])
+                        .ok_or(CountOverflow)?;
+                }
+                ways[i][j] = count;
+            }
+        }
+
+        Ok(Self { record, ways })
+    }
+
+    pub fn count(&self) -> u128 {
+        self.ways[0][0]
+    }
+
+    // the arrangement at `index` when they're sorted with operational before damaged
+    pub fn nth(&self, mut index: u128) -> Option<Vec<Status>> {
+        if index >= self.count() {
+            return None;
+        }
+
+        let (springs, groups) = (&self.record.springs, &self.record.groups);
+        let mut arrangement = Vec::with_capacity(springs.len());
+        let (mut i, mut j) = (0, 0);
+        while i < springs.len() {
+            // the counts make sure whichever choice is taken still has `index` options left
+            if springs[i] != Status::Damaged {
+                let operational = self.ways[i + 1][j];
+                if index < operational {
+                    arrangement.push(Status::Operational);
+                    i += 1;
+                    continue;
+                }
+                index -= operational;
+            }
+
+            let len = groups[j];
+            arrangement.extend(std::iter::repeat_n(Status::Damaged, len));
+            if i + len < springs.len() {
+                arrangement.push(Status::Operational);
+            }
+            i += len + 1;
+            j += 1;
+        }
+
+        Some(arrangement)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Vec<Status>> + '_ {
+        (0..self.count()).map_while(|index| self.nth(index))
+    }
+
+    // one arrangement picked evenly at random, from two calls to `random` per draw.
+    // Draws past the last whole multiple of the count are redrawn, so taking them
+    // modulo the count doesn't favour the low indices
+    pub fn sample(&self, mut random: impl FnMut() -> u64) -> Option<Vec<Status>> {
+        let count = self.count();
+        if count == 0 {
+            return None;
+        }
+        let limit = u128::MAX - u128::MAX % count;
+        let index = loop {
+            let draw = (random() as u128) << 64 | random() as u128;
+            if draw < limit {
+                break draw % count;
+            }
+        };
+        self.nth(index)
+    }
+}
+
+pub fn solve(records: &[Record], unfold: usize) -> Result<u128, CountOverflow> {
+    records.iter().try_fold(0_u128, |total, record| {
+        let count = Arrangements::new(&record.unfold(unfold))?.count();
+        total.checked_add(count).ok_or(CountOverflow)
+    })
+}
+
+// how many copies of each record to join, for each part
+pub struct Params {
+    pub part1: usize,
+    pub part2: usize,
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    type Parsed<'a> = Vec<Record>;
+    type Params = Params;
+    const PARAMS: Params = Params { part1: 1, part2: 5 };
+
+    fn parse(input: &str) -> Result<Vec<Record>, ParseError> {
+        parse(&input.lines().collect::<Vec<_>>())
+    }
+
+    fn part1(records: &Vec<Record>, params: &Params) -> Answer {
+        solve(records, params.part1).into()
+    }
+
+    fn part2(records: &Vec<Record>, params: &Params) -> Answer {
+        solve(records, params.part2).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use utils::rng::Lcg;
+
+    use super::*;
+
+    const INPUT: &[&str] = &[
+        "???.### 1,1,3",
+        ".??..??...?##. 1,1,3",
+        "?#?#?#?#?#?#?#? 1,3,1,6",
+        "????.#...#... 4,1,1",
+        "????.######..#####. 1,6,5",
+        "?###???????? 3,2,1",
+    ];
+
+    fn symbols(springs: &[Status]) -> String {
+        springs.iter().map(|s| s.symbol()).collect()
+    }
+
+    #[test]
+    fn test_validation() {
+        let record = &parse(&[".??..??...?##. 1,1,3"]).unwrap()[0];
+        let springs = parse(&["..#...#...###. 1"]).unwrap()[0].springs.clone();
+        assert!(record.matches(&springs));
+        assert!(!record.matches(&springs[1..]));
+        let springs = parse(&["..##......###. 1"]).unwrap()[0].springs.clone();
+        assert!(!record.matches(&springs));
+    }
+
+    #[test]
+    fn test1() {
+        let counts = [1, 4, 1, 1, 4, 10];
+        for (line, expected) in INPUT.iter().zip(counts) {
+            assert_eq!(solve(&parse(&[line]).unwrap(), 1), Ok(expected), "{line}");
+        }
+        assert_eq!(solve(&parse(INPUT).unwrap(), 1), Ok(21));
+    }
+
+    #[test]
+    fn test2() {
+        let counts = [1, 16384, 1, 16, 2500, 506250];
+        for (line, expected) in INPUT.iter().zip(counts) {
+            assert_eq!(solve(&parse(&[line]).unwrap(), 5), Ok(expected), "{line}");
+        }
+        assert_eq!(solve(&parse(INPUT).unwrap(), 5), Ok(525152));
+    }
+
+    #[test]
+    fn unfolding() {
+        let record = &parse(&[".# 1"]).unwrap()[0];
+        assert_eq!(symbols(&record.unfold(3).springs), ".#?.#?.#");
+        assert_eq!(record.unfold(3).groups, [1, 1, 1]);
+        assert_eq!(Arrangements::new(&record.unfold(0)).unwrap().count(), 1);
+        // each extra copy multiplies the count by 15, this is far past a u64
+        let record = &parse(&["?###???????? 3,2,1"]).unwrap()[0];
+        assert_eq!(
+            Arrangements::new(&record.unfold(30)).unwrap().count(),
+            10 * 15_u128.pow(29)
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let err = parse(&["#.# 1,0"]).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 7, "0"));
+        assert_eq!(err.expected, "a group size above 0");
+
+        // 10 * 15^39 arrangements, and twelve records of 10 * 15^31 that each fit
+        let record = &parse(&["?###???????? 3,2,1"]).unwrap()[0];
+        assert!(Arrangements::new(&record.unfold(40)).is_err());
+        let records = vec![record.unfold(32); 12];
+        assert!(Arrangements::new(&records[0]).is_ok());
+        assert_eq!(solve(&records, 1), Err(CountOverflow));
+        assert_eq!(
+            Day12::part1(&records, &Day12::PARAMS),
+            Answer::Error(CountOverflow.to_string())
+        );
+    }
+
+    // every way to fill in the unknowns that fits, sorted like `Arrangements::nth`
+    fn brute_force(record: &Record) -> Vec<Vec<Status>> {
+        let unknowns: Vec<_> = (0..record.springs.len())
+            .filter(|i| record.springs[*i] == Status::Unknown)
+            .collect();
+        let mut all: Vec<_> = (0..1_u32 << unknowns.len())
+            .map(|bits| {
+                let mut springs = record.springs.clone();
+                for (bit, i) in unknowns.iter().enumerate() {
+                    springs[*i] = if bits >> bit & 1 == 1 {
+                        Status::Damaged
+                    } else {
+                        Status::Operational
+                    };
+                }
+                springs
+            })
+            .filter(|springs| record.matches(springs))
+            .collect();
+        all.sort_by_key(|springs| symbols(springs).replace('.', "0").replace('#', "1"));
+        all
+    }
+
+    #[test]
+    fn enumeration() {
+        for record in parse(INPUT).unwrap() {
+            let arrangements = Arrangements::new(&record).unwrap();
+            assert_eq!(
+                arrangements.iter().collect::<Vec<_>>(),
+                brute_force(&record)
+            );
+            assert_eq!(arrangements.nth(arrangements.count()), None);
+        }
+
+        let record = &parse(&["?###???????? 3,2,1"]).unwrap()[0];
+        let arrangements = Arrangements::new(record).unwrap();
+        assert_eq!(symbols(&arrangements.nth(0).unwrap()), ".###....##.#");
+        assert_eq!(symbols(&arrangements.nth(9).unwrap()), ".###.##.#...");
+
+        let impossible = &parse(&["#.# 3"]).unwrap()[0];
+        assert_eq!(Arrangements::new(impossible).unwrap().count(), 0);
+        assert_eq!(Arrangements::new(impossible).unwrap().sample(|| 0), None);
+    }
+
+    #[test]
+    fn sampling() {
+        let mut rng = Lcg::new(12);
+        let mut random = || rng.next_u64();
+
+        let record = parse(&["?###???????? 3,2,1"]).unwrap()[0].unfold(5);
+        let arrangements = Arrangements::new(&record).unwrap();
+        let mut seen = std::collections::HashSet::new();
+        for _ in 0..100 {
+            let sample = arrangements.sample(&mut random).unwrap();
+            assert!(record.matches(&sample), "{}", symbols(&sample));
+            seen.insert(sample);
+        }
+        // out of 506250 there should hardly be any repeats
+        assert!(seen.len() > 95);
+
+        // with 3 arrangements, 2^128 - 1 is in the uneven tail and gets redrawn
+        let records = parse(&["??? 1"]).unwrap();
+        let arrangements = Arrangements::new(&records[0]).unwrap();
+        let mut draws = [u64::MAX, u64::MAX, 0, 4].into_iter();
+        let sample = arrangements.sample(|| draws.next().unwrap()).unwrap();
+        assert_eq!(Some(sample), arrangements.nth(1));
+        assert_eq!(draws.next(), None);
+    }
+}
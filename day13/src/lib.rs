@@ -0,0 +1,243 @@
+use utils::{parse::ParseError, Answer, Grid2D, Point, Solution};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum GroundType {
+    Ash,
+    Rock,
+}
+
+pub type GroundGrid = Grid2D<GroundType>;
+
+pub fn parse(input: &[&str]) -> Result<Vec<GroundGrid>, ParseError> {
+    let mut maps = Vec::new();
+    let mut start = 0;
+
+    for end in (0..=input.len()).filter(|i| input.get(*i).is_none_or(|l| l.is_empty())) {
+        // extra blank lines between maps or at the end don't start a new one
+        if start == end {
+            start = end + 1;
+            continue;
+        }
+        let map = Grid2D::parse(&input[start..end], "ash or rock", |chr| match chr {
+            '.' => Some(GroundType::Ash),
+            '#' => Some(GroundType::Rock),
+            _ => None,
+        })
+        // errors count lines from the start of the map
+        .map_err(|e| ParseError {
+            line: e.line + start,
+            ..e
+        })?;
+        maps.push(map);
+        start = end + 1;
+    }
+
+    Ok(maps)
+}
+
+pub fn solve1(maps: &[GroundGrid]) -> u32 {
+    let mut total = 0;
+
+    'outer: for map in maps {
+        // check for mirror on rows
+        for possible_horiz_mirror_idx in
+            (0..map.height() - 1).filter(|i| map.row(*i) == map.row(*i + 1))
+        {
+            // validate if this is the mirror for the full map
+            if (0..possible_horiz_mirror_idx)
+                .rev()
+                .zip(possible_horiz_mirror_idx + 2..map.height())
+                .all(|(l, r)| map.row(l) == map.row(r))
+            {
+                total += 100 * (possible_horiz_mirror_idx as u32 + 1);
+                continue 'outer;
+            }
+        }
+
+        // check for mirror on cols
+        for possible_vert_mirror_idx in
+            (0..map.width() - 1).filter(|i| map.column(*i).eq(map.column(*i + 1)))
+        {
+            // validate if this is mirror for the full map
+            if (0..possible_vert_mirror_idx)
+                .rev()
+                .zip(possible_vert_mirror_idx + 2..map.width())
+                .all(|(l, r)| map.column(l).eq(map.column(r)))
+            {
+                total += possible_vert_mirror_idx as u32 + 1;
+                break;
+            }
+        }
+    }
+
+    total
+}
+
+pub fn solve2(maps: &[GroundGrid]) -> u32 {
+    let mut total = 0;
+
+    // Get original reflection lines
+    let mut original_reflection_line = Vec::new();
+    'outer: for map in maps.iter() {
+        // check for mirror on rows
+        for possible_horiz_mirror_idx in
+            (0..map.height() - 1).filter(|i| map.row(*i) == map.row(*i + 1))
+        {
+            // validate if this is the mirror for the full map
+            if (0..possible_horiz_mirror_idx)
+                .rev()
+                .zip(possible_horiz_mirror_idx + 2..map.height())
+                .all(|(l, r)| map.row(l) == map.row(r))
+            {
+                original_reflection_line.push((Some(possible_horiz_mirror_idx), None));
+                continue 'outer;
+            }
+        }
+
+        // check for mirror on cols
+        for possible_vert_mirror_idx in
+            (0..map.width() - 1).filter(|i| map.column(*i).eq(map.column(*i + 1)))
+        {
+            // validate if this is mirror for the full map
+            if (0..possible_vert_mirror_idx)
+                .rev()
+                .zip(possible_vert_mirror_idx + 2..map.width())
+                .all(|(l, r)| map.column(l).eq(map.column(r)))
+            {
+                original_reflection_line.push((None, Some(possible_vert_mirror_idx)));
+                break;
+            }
+        }
+    }
+
+    'outer: for (map, orig_reflection_line) in maps.iter().zip(original_reflection_line.iter()) {
+        for y in 0..map.height() {
+            for x in 0..map.width() {
+                // place smudge here
+                let mut smudged_map = map.clone();
+                let smudge = Point { x, y };
+                if let GroundType::Ash = smudged_map[smudge] {
+                    smudged_map[smudge] = GroundType::Rock;
+                } else {
+                    smudged_map[smudge] = GroundType::Ash;
+                }
+
+                // check for mirror on rows
+                for possible_horiz_mirror_idx in (0..smudged_map.height() - 1)
+                    .filter(|i| smudged_map.row(*i) == smudged_map.row(*i + 1))
+                {
+                    // make sure this is a new reflection
+                    if let Some(orig_horiz_line) = orig_reflection_line.0 {
+                        if possible_horiz_mirror_idx == orig_horiz_line {
+                            continue;
+                        }
+                    }
+                    // validate if this is the mirror for the full smudged_map
+                    if (0..possible_horiz_mirror_idx)
+                        .rev()
+                        .zip(possible_horiz_mirror_idx + 2..smudged_map.height())
+                        .all(|(l, r)| smudged_map.row(l) == smudged_map.row(r))
+                    {
+                        total += 100 * (possible_horiz_mirror_idx as u32 + 1);
+                        continue 'outer;
+                    }
+                }
+
+                // check for mirror on cols
+                for possible_vert_mirror_idx in (0..smudged_map.width() - 1)
+                    .filter(|i| smudged_map.column(*i).eq(smudged_map.column(*i + 1)))
+                {
+                    // make sure this is a new reflection
+                    if let Some(orig_vert_line) = orig_reflection_line.1 {
+                        if possible_vert_mirror_idx == orig_vert_line {
+                            continue;
+                        }
+                    }
+                    // validate if this is mirror for the full smudged_map
+                    if (0..possible_vert_mirror_idx)
+                        .rev()
+                        .zip(possible_vert_mirror_idx + 2..smudged_map.width())
+                        .all(|(l, r)| smudged_map.column(l).eq(smudged_map.column(r)))
+                    {
+                        total += possible_vert_mirror_idx as u32 + 1;
+                        continue 'outer;
+                    }
+                }
+            }
+        }
+    }
+
+    total
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    type Parsed<'a> = Vec<GroundGrid>;
+    type Params = ();
+    const PARAMS: () = ();
+
+    fn parse(input: &str) -> Result<Vec<GroundGrid>, ParseError> {
+        parse(&input.lines().collect::<Vec<_>>())
+    }
+
+    fn part1(maps: &Vec<GroundGrid>, _: &()) -> Answer {
+        solve1(maps).into()
+    }
+
+    fn part2(maps: &Vec<GroundGrid>, _: &()) -> Answer {
+        solve2(maps).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[&str] = &[
+        "#.##..##.",
+        "..#.##.#.",
+        "##......#",
+        "##......#",
+        "..#.##.#.",
+        "..##..##.",
+        "#.#.##.#.",
+        "",
+        "#...##..#",
+        "#....#..#",
+        "..##..###",
+        "#####.##.",
+        "#####.##.",
+        "..##..###",
+        "#....#..#",
+    ];
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve1(&parse(INPUT).unwrap()), 405)
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve2(&parse(INPUT).unwrap()), 400)
+    }
+
+    #[test]
+    fn blank_lines() {
+        let mut input = vec!["", ""];
+        input.extend(&INPUT[..8]);
+        input.extend([""]);
+        input.extend(&INPUT[8..]);
+        input.extend(["", ""]);
+        let maps = parse(&input).unwrap();
+        assert_eq!(maps.len(), 2);
+        assert_eq!((solve1(&maps), solve2(&maps)), (405, 400));
+        assert_eq!(parse(&["", ""]).unwrap(), []);
+
+        // lines are still counted from the start of the input
+        input[12] = "#..x.#..#";
+        let err = parse(&input).unwrap_err();
+        assert_eq!((err.line, err.column), (13, 4));
+    }
+}
@@ -0,0 +1,467 @@
+use utils::{cycle, parse::ParseError, Answer, Direction, Grid2D, Point, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TileType {
+    RoundRock,
+    CubeRock,
+    Ground,
+}
+
+pub fn parse(input: &[&str]) -> Result<Grid2D<TileType>, ParseError> {
+    Grid2D::parse(input, "a rock or ground", |c| match c {
+        'O' => Some(TileType::RoundRock),
+        '#' => Some(TileType::CubeRock),
+        '.' => Some(TileType::Ground),
+        _ => None,
+    })
+}
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+// the round rocks on a `Board`, one bit per cell, row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rocks(Vec<u64>);
+
+// everything about a platform that doesn't move, stored as bitsets so a whole tilt
+// works on runs of cells between cube rocks instead of one rock at a time
+#[derive(Debug, Clone)]
+pub struct Board {
+    width: usize,
+    height: usize,
+    // u64s per row, rows start on a word boundary
+    words: usize,
+    // u64s per column when the bits are transposed for tilting north or south
+    column_words: usize,
+    cubes: Vec<u64>,
+    // (y, start, end) for every run of cells in a row without cube rocks
+    row_segments: Vec<(usize, usize, usize)>,
+    // (x, start, end) for every run of cells in a column without cube rocks
+    column_segments: Vec<(usize, usize, usize)>,
+}
+
+// (word index, mask) pairs covering bits start..end
+fn masks(start: usize, end: usize) -> impl Iterator<Item = (usize, u64)> {
+    let words = if start < end {
+        start / WORD_BITS..(end - 1) / WORD_BITS + 1
+    } else {
+        0..0
+    };
+    words.map(move |word| {
+        let lo = start.max(word * WORD_BITS) - word * WORD_BITS;
+        let hi = end.min((word + 1) * WORD_BITS) - word * WORD_BITS;
+        let mask = if hi - lo == WORD_BITS {
+            u64::MAX
+        } else {
+            ((1 << (hi - lo)) - 1) << lo
+        };
+        (word, mask)
+    })
+}
+
+// positions of the set bits in one row
+fn set_bits(row: &[u64]) -> impl Iterator<Item = usize> + '_ {
+    row.iter().enumerate().flat_map(|(word, bits)| {
+        let mut bits = *bits;
+        std::iter::from_fn(move || {
+            (bits != 0).then(|| {
+                let bit = bits.trailing_zeros() as usize;
+                bits &= bits - 1;
+                word * WORD_BITS + bit
+            })
+        })
+    })
+}
+
+// runs of cells without a set bit, in `lines` lines of `len` bits each
+fn segments(bits: &[u64], lines: usize, len: usize) -> Vec<(usize, usize, usize)> {
+    let words = len.div_ceil(WORD_BITS);
+    let mut segments = Vec::new();
+    for line in 0..lines {
+        let mut start = 0;
+        for end in set_bits(&bits[line * words..(line + 1) * words]).chain([len]) {
+            if start < end {
+                segments.push((line, start, end));
+            }
+            start = end + 1;
+        }
+    }
+    segments
+}
+
+// moves every set bit of a segment against its start or its end
+fn pack(bits: &mut [u64], words: usize, segments: &[(usize, usize, usize)], to_start: bool) {
+    for &(line, start, end) in segments {
+        let line = &mut bits[line * words..(line + 1) * words];
+        let mut count = 0;
+        for (word, mask) in masks(start, end) {
+            count += (line[word] & mask).count_ones() as usize;
+            line[word] &= !mask;
+        }
+        let packed = if to_start {
+            masks(start, start + count)
+        } else {
+            masks(end - count, end)
+        };
+        for (word, mask) in packed {
+            line[word] |= mask;
+        }
+    }
+}
+
+// swaps rows and columns of a 64x64 block of bits, by swapping ever smaller
+// off-diagonal quarters of it
+fn transpose_block(block: &mut [u64; WORD_BITS]) {
+    let mut half = WORD_BITS / 2;
+    let mut mask = u64::MAX >> half;
+    while half != 0 {
+        let mut k = 0;
+        while k < WORD_BITS {
+            let swap = ((block[k] >> half) ^ block[k + half]) & mask;
+            block[k] ^= swap << half;
+            block[k + half] ^= swap;
+            k = (k + half + 1) & !half;
+        }
+        half /= 2;
+        mask ^= mask << half;
+    }
+}
+
+// `lines` lines of `len` bits each into `len` lines of `lines` bits each, a block
+// of 64 lines by 64 bits at a time
+fn transpose(bits: &[u64], lines: usize, len: usize) -> Vec<u64> {
+    let (words, out_words) = (len.div_ceil(WORD_BITS), lines.div_ceil(WORD_BITS));
+    let mut out = vec![0; len * out_words];
+    for out_word in 0..out_words {
+        for word in 0..words {
+            let mut block = [0; WORD_BITS];
+            for (i, row) in block.iter_mut().enumerate() {
+                let line = out_word * WORD_BITS + i;
+                if line < lines {
+                    *row = bits[line * words + word];
+                }
+            }
+            transpose_block(&mut block);
+            for (i, column) in block.iter().enumerate() {
+                let out_line = word * WORD_BITS + i;
+                if out_line < len {
+                    out[out_line * out_words + out_word] = *column;
+                }
+            }
+        }
+    }
+    out
+}
+
+impl Board {
+    pub fn new(grid: &Grid2D<TileType>) -> (Self, Rocks) {
+        let words = grid.width().div_ceil(WORD_BITS);
+        let mut cubes = vec![0; words * grid.height()];
+        let mut rocks = vec![0; words * grid.height()];
+        for (p, tile) in grid.iter() {
+            let bits = match tile {
+                TileType::RoundRock => &mut rocks,
+                TileType::CubeRock => &mut cubes,
+                TileType::Ground => continue,
+            };
+            bits[p.y * words + p.x / WORD_BITS] |= 1 << (p.x % WORD_BITS);
+        }
+
+        let row_segments = segments(&cubes, grid.height(), grid.width());
+        let column_segments = segments(
+            &transpose(&cubes, grid.height(), grid.width()),
+            grid.width(),
+            grid.height(),
+        );
+
+        let board = Self {
+            width: grid.width(),
+            height: grid.height(),
+            words,
+            column_words: grid.height().div_ceil(WORD_BITS),
+            cubes,
+            row_segments,
+            column_segments,
+        };
+        (board, Rocks(rocks))
+    }
+
+    pub fn tilt(&self, rocks: &mut Rocks, dir: Direction) {
+        let to_start = matches!(dir, Direction::Up | Direction::Left);
+        match dir {
+            Direction::Left | Direction::Right => {
+                pack(&mut rocks.0, self.words, &self.row_segments, to_start)
+            }
+            Direction::Up | Direction::Down => {
+                // the same packing on the transposed rocks, column by column
+                let mut columns = transpose(&rocks.0, self.height, self.width);
+                pack(
+                    &mut columns,
+                    self.column_words,
+                    &self.column_segments,
+                    to_start,
+                );
+                rocks.0 = transpose(&columns, self.width, self.height);
+            }
+        }
+    }
+
+    // north, west, south, east
+    pub fn spin_cycle(&self, rocks: &Rocks) -> Rocks {
+        let mut rocks = rocks.clone();
+        for dir in [
+            Direction::Up,
+            Direction::Left,
+            Direction::Down,
+            Direction::Right,
+        ] {
+            self.tilt(&mut rocks, dir);
+        }
+
+        rocks
+    }
+
+    pub fn to_grid(&self, rocks: &Rocks) -> Grid2D<TileType> {
+        let is_set = |bits: &[u64], p: Point| {
+            bits[p.y * self.words + p.x / WORD_BITS] & (1 << (p.x % WORD_BITS)) != 0
+        };
+        Grid2D::from_fn(self.width, self.height, |p| {
+            if is_set(&self.cubes, p) {
+                TileType::CubeRock
+            } else if is_set(&rocks.0, p) {
+                TileType::RoundRock
+            } else {
+                TileType::Ground
+            }
+        })
+    }
+}
+
+// rolls every round rock as far as it goes towards `dir`
+pub fn tilt(grid: &mut Grid2D<TileType>, dir: Direction) {
+    let (board, mut rocks) = Board::new(grid);
+    board.tilt(&mut rocks, dir);
+    *grid = board.to_grid(&rocks);
+}
+
+// the layout after `cycles` spin cycles
+pub fn spin(grid: &Grid2D<TileType>, cycles: usize) -> Grid2D<TileType> {
+    let (board, rocks) = Board::new(grid);
+    // the rocks settle into a loop of layouts long before any big cycle count, but
+    // small ones are just simulated
+    match cycle::find_hashed_within(rocks, |rocks| board.spin_cycle(rocks), cycles) {
+        Ok(history) => board.to_grid(history.state_at(cycles)),
+        Err(rocks) => board.to_grid(&rocks),
+    }
+}
+
+// load on the support beams along the `beam` edge, each rock weighs as much as
+// its distance from the opposite edge
+pub fn load(grid: &Grid2D<TileType>, beam: Direction) -> u64 {
+    grid.iter()
+        .filter(|(_, tile)| matches!(tile, TileType::RoundRock))
+        .map(|(p, _)| match beam {
+            Direction::Up => grid.height() - p.y,
+            Direction::Down => p.y + 1,
+            Direction::Left => grid.width() - p.x,
+            Direction::Right => p.x + 1,
+        } as u64)
+        .sum()
+}
+
+pub fn solve1(grid: &Grid2D<TileType>) -> u64 {
+    let mut grid = grid.clone();
+    tilt(&mut grid, Direction::Up);
+
+    load(&grid, Direction::Up)
+}
+
+pub fn solve2(grid: &Grid2D<TileType>, cycles: usize) -> u64 {
+    load(&spin(grid, cycles), Direction::Up)
+}
+
+pub struct Params {
+    pub spin_cycles: usize,
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    type Parsed<'a> = Grid2D<TileType>;
+    type Params = Params;
+    const PARAMS: Params = Params {
+        spin_cycles: 1000000000,
+    };
+
+    fn parse(input: &str) -> Result<Grid2D<TileType>, ParseError> {
+        parse(&input.lines().collect::<Vec<_>>())
+    }
+
+    fn part1(grid: &Grid2D<TileType>, _: &Params) -> Answer {
+        solve1(grid).into()
+    }
+
+    fn part2(grid: &Grid2D<TileType>, params: &Params) -> Answer {
+        solve2(grid, params.spin_cycles).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use utils::rng::Lcg;
+
+    use super::*;
+
+    const INPUT: &[&str] = &[
+        "O....#....",
+        "O.OO#....#",
+        ".....##...",
+        "OO.#O....O",
+        ".O.....O#.",
+        "O.#..O.#.#",
+        "..O..#O..O",
+        ".......O..",
+        "#....###..",
+        "#OO..#....",
+    ];
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve1(&parse(INPUT).unwrap()), 136)
+    }
+
+    #[test]
+    fn spin_layouts() {
+        let grid = parse(INPUT).unwrap();
+        let after = |cycles| spin(&grid, cycles);
+        assert_eq!(after(0), grid);
+        assert_eq!(
+            after(1),
+            parse(&[
+                ".....#....",
+                "....#...O#",
+                "...OO##...",
+                ".OO#......",
+                ".....OOO#.",
+                ".O#...O#.#",
+                "....O#....",
+                "......OOOO",
+                "#...O###..",
+                "#..OO#....",
+            ])
+            .unwrap()
+        );
+        assert_eq!(after(3), after(10));
+        assert_eq!(load(&after(1), Direction::Up), 87);
+
+        // tilting north packs every rock against the north beam
+        let mut tilted = grid.clone();
+        tilt(&mut tilted, Direction::Up);
+        assert_eq!(load(&tilted, Direction::Up), 136);
+        assert_eq!(
+            load(&grid, Direction::Down) + load(&grid, Direction::Up),
+            18 * 11
+        );
+
+        // a board this full of rocks weighs more than a u32 holds
+        let full = Grid2D::new(3000, 3000, TileType::RoundRock);
+        assert_eq!(load(&full, Direction::Up), 3000 * (3000 * 3001 / 2));
+    }
+
+    // moves one rock at a time, to check the bitboard against
+    fn naive_tilt(grid: &mut Grid2D<TileType>, dir: Direction) {
+        match dir {
+            Direction::Up => {
+                for y in 0..grid.height() {
+                    for x in 0..grid.width() {
+                        if let TileType::RoundRock = grid[Point { x, y }] {
+                            // roll it up
+                            let rock_new_y = (0..y)
+                                .rev()
+                                .find(|cy| !matches!(grid[Point { x, y: *cy }], TileType::Ground))
+                                .map(|ny| ny + 1)
+                                .unwrap_or_default();
+                            grid[Point { x, y }] = TileType::Ground;
+                            grid[Point { x, y: rock_new_y }] = TileType::RoundRock;
+                        }
+                    }
+                }
+            }
+            Direction::Left => {
+                for y in 0..grid.height() {
+                    for x in 0..grid.width() {
+                        if let TileType::RoundRock = grid[Point { x, y }] {
+                            // roll it left
+                            let rock_new_x = (0..x)
+                                .rev()
+                                .find(|cx| !matches!(grid[Point { x: *cx, y }], TileType::Ground))
+                                .map(|nx| nx + 1)
+                                .unwrap_or_default();
+                            grid[Point { x, y }] = TileType::Ground;
+                            grid[Point { x: rock_new_x, y }] = TileType::RoundRock;
+                        }
+                    }
+                }
+            }
+            Direction::Down => {
+                for y in (0..grid.height()).rev() {
+                    for x in 0..grid.width() {
+                        if let TileType::RoundRock = grid[Point { x, y }] {
+                            // roll it down
+                            let rock_new_y = (y + 1..grid.height())
+                                .find(|cy| !matches!(grid[Point { x, y: *cy }], TileType::Ground))
+                                .map(|ny| ny - 1)
+                                .unwrap_or(grid.height() - 1);
+                            grid[Point { x, y }] = TileType::Ground;
+                            grid[Point { x, y: rock_new_y }] = TileType::RoundRock;
+                        }
+                    }
+                }
+            }
+            Direction::Right => {
+                for y in 0..grid.height() {
+                    for x in (0..grid.width()).rev() {
+                        if let TileType::RoundRock = grid[Point { x, y }] {
+                            // roll it right
+                            let rock_new_x = (x + 1..grid.width())
+                                .find(|cx| !matches!(grid[Point { x: *cx, y }], TileType::Ground))
+                                .map(|nx| nx - 1)
+                                .unwrap_or(grid.width() - 1);
+                            grid[Point { x, y }] = TileType::Ground;
+                            grid[Point { x: rock_new_x, y }] = TileType::RoundRock;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bitboard_tilts() {
+        // wider than a word, with random rocks
+        let mut rng = Lcg::new(12345);
+        let grid = Grid2D::from_fn(150, 40, |_| match rng.below(8) {
+            0 | 1 => TileType::RoundRock,
+            2 => TileType::CubeRock,
+            _ => TileType::Ground,
+        });
+
+        let (board, rocks) = Board::new(&grid);
+        assert_eq!(board.to_grid(&rocks), grid);
+        let columns = transpose(&rocks.0, grid.height(), grid.width());
+        assert_eq!(columns.len(), 150);
+        assert_eq!(transpose(&columns, grid.width(), grid.height()), rocks.0);
+        for dir in Direction::ALL {
+            let mut expected = grid.clone();
+            naive_tilt(&mut expected, dir);
+            let mut tilted = rocks.clone();
+            board.tilt(&mut tilted, dir);
+            assert_eq!(board.to_grid(&tilted), expected, "tilting {dir:?}");
+        }
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve2(&parse(INPUT).unwrap(), 1000000000), 64)
+    }
+}
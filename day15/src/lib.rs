@@ -0,0 +1,172 @@
+use utils::{
+    parse::{Line, ParseError},
+    Answer, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Remove,
+    Insert(u32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step<'a> {
+    // the whole step, which is what part 1 hashes
+    pub text: &'a str,
+    pub label: &'a str,
+    pub operation: Operation,
+}
+
+pub fn parse(input: &str) -> Result<Vec<Step<'_>>, ParseError> {
+    let mut lines = input.lines().enumerate().map(|(idx, l)| Line::new(idx, l));
+    let line = lines
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(0, "a sequence of steps"))?;
+    let mut steps = Vec::new();
+    for text in line.text.trim_end().split(',') {
+        let Some(op_idx) = text.find(['-', '=']) else {
+            return Err(line.error(text, "a step with an operation (- or =)"));
+        };
+        let (label, op, focal_length) = (
+            &text[..op_idx],
+            &text[op_idx..op_idx + 1],
+            &text[op_idx + 1..],
+        );
+        if label.is_empty() {
+            return Err(line.error(op, "a label"));
+        }
+        let operation = match op {
+            "-" if !focal_length.is_empty() => {
+                return Err(line.error(focal_length, "nothing after a removal"))
+            }
+            "-" => Operation::Remove,
+            _ => Operation::Insert(line.parse(focal_length, "a focal length")?),
+        };
+        steps.push(Step {
+            text,
+            label,
+            operation,
+        });
+    }
+
+    if let Some(line) = lines.find(|l| !l.text.trim().is_empty()) {
+        return Err(line.error(line.text, "the end of input"));
+    }
+
+    Ok(steps)
+}
+
+fn run_hash(input: &str) -> u32 {
+    let mut cur_val = 0;
+
+    for chr in input.bytes() {
+        cur_val += chr as u32;
+        cur_val *= 17;
+        cur_val %= 256;
+    }
+
+    cur_val
+}
+
+pub fn solve1(steps: &[Step]) -> u32 {
+    steps.iter().map(|step| run_hash(step.text)).sum()
+}
+
+pub fn solve2(steps: &[Step]) -> u32 {
+    let mut boxes: Vec<Vec<(&str, u32)>> = vec![Vec::new(); 256];
+    for step in steps {
+        let lenses = &mut boxes[run_hash(step.label) as usize];
+        let existing = lenses.iter().position(|(l, _)| *l == step.label);
+        match (step.operation, existing) {
+            (Operation::Remove, Some(lens_idx)) => {
+                lenses.remove(lens_idx);
+            }
+            (Operation::Remove, None) => {}
+            (Operation::Insert(focal_length), Some(lens_idx)) => {
+                lenses[lens_idx] = (step.label, focal_length)
+            }
+            (Operation::Insert(focal_length), None) => lenses.push((step.label, focal_length)),
+        }
+    }
+
+    boxes
+        .iter()
+        .enumerate()
+        .map(|(box_idx, b)| {
+            b.iter()
+                .enumerate()
+                .map(|(lense_idx, (_, focal_length))| {
+                    (box_idx + 1) * (1 + lense_idx) * *focal_length as usize
+                })
+                .sum::<usize>()
+        })
+        .sum::<usize>() as u32
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    type Parsed<'a> = Vec<Step<'a>>;
+    type Params = ();
+    const PARAMS: () = ();
+
+    fn parse(input: &str) -> Result<Vec<Step<'_>>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(steps: &Vec<Step>, _: &()) -> Answer {
+        solve1(steps).into()
+    }
+
+    fn part2(steps: &Vec<Step>, _: &()) -> Answer {
+        solve2(steps).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+    #[test]
+    fn test_run_hash() {
+        assert_eq!(run_hash("HASH"), 52);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse(INPUT).unwrap()[..2],
+            [
+                Step {
+                    text: "rn=1",
+                    label: "rn",
+                    operation: Operation::Insert(1)
+                },
+                Step {
+                    text: "cm-",
+                    label: "cm",
+                    operation: Operation::Remove
+                }
+            ]
+        );
+        let err = parse("rn=1,cm,qp=3").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (6, "cm"));
+        let err = parse("rn=1,cm=x").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (9, "x"));
+        let err = parse("rn=1,=3").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (6, "="));
+    }
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve1(&parse(INPUT).unwrap()), 1320)
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve2(&parse(INPUT).unwrap()), 145)
+    }
+}
@@ -0,0 +1,246 @@
+use std::collections::HashSet;
+
+use utils::{parse::ParseError, Answer, Direction, Grid2D, Point, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TileType {
+    Empty,
+    Mirror(MirrorQuadrant),
+    Splitter(SplitDirection),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MirrorQuadrant {
+    NorthEast,
+    NorthWest,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitDirection {
+    Horizontal,
+    Vertical,
+}
+
+fn reflect(light_dir: Direction, mirror: MirrorQuadrant) -> Direction {
+    match (light_dir, mirror) {
+        (Direction::Up, MirrorQuadrant::NorthEast) => Direction::Left,
+        (Direction::Up, MirrorQuadrant::NorthWest) => Direction::Right,
+        (Direction::Right, MirrorQuadrant::NorthEast) => Direction::Down,
+        (Direction::Right, MirrorQuadrant::NorthWest) => Direction::Up,
+        (Direction::Down, MirrorQuadrant::NorthEast) => Direction::Right,
+        (Direction::Down, MirrorQuadrant::NorthWest) => Direction::Left,
+        (Direction::Left, MirrorQuadrant::NorthEast) => Direction::Up,
+        (Direction::Left, MirrorQuadrant::NorthWest) => Direction::Down,
+    }
+}
+
+fn split(light_dir: Direction, splitter: SplitDirection) -> (Direction, Option<Direction>) {
+    match (light_dir, splitter) {
+        (Direction::Up | Direction::Down, SplitDirection::Horizontal) => {
+            (Direction::Left, Some(Direction::Right))
+        }
+        (Direction::Up | Direction::Down, SplitDirection::Vertical) => (light_dir, None),
+        (Direction::Left | Direction::Right, SplitDirection::Horizontal) => (light_dir, None),
+        (Direction::Left | Direction::Right, SplitDirection::Vertical) => {
+            (Direction::Up, Some(Direction::Down))
+        }
+    }
+}
+
+fn move_light(
+    light_dir: Direction,
+    light_location: Point,
+    grid_width: usize,
+    grid_height: usize,
+) -> Option<Point> {
+    light_dir
+        .step(light_location)
+        .filter(|p| p.x < grid_width && p.y < grid_height)
+}
+
+pub fn parse(input: &[&str]) -> Result<Grid2D<TileType>, ParseError> {
+    Grid2D::parse(input, "a mirror, splitter or empty space", |c| match c {
+        '.' => Some(TileType::Empty),
+        '/' => Some(TileType::Mirror(MirrorQuadrant::NorthWest)),
+        '\\' => Some(TileType::Mirror(MirrorQuadrant::NorthEast)),
+        '-' => Some(TileType::Splitter(SplitDirection::Horizontal)),
+        '|' => Some(TileType::Splitter(SplitDirection::Vertical)),
+        _ => None,
+    })
+}
+
+pub fn solve1(grid: &Grid2D<TileType>) -> i64 {
+    let mut lit_tiles = Grid2D::new(grid.width(), grid.height(), false);
+    let mut cur_lights = vec![(Direction::Right, Point { x: 0, y: 0 })];
+    let mut light_loop_detect = HashSet::new();
+    while let Some((light_dir, light_loc)) = cur_lights.pop() {
+        if !light_loop_detect.insert((light_dir, light_loc)) {
+            continue;
+        }
+
+        lit_tiles[light_loc] = true;
+        match grid[light_loc] {
+            TileType::Empty => {
+                if let Some(light_point) =
+                    move_light(light_dir, light_loc, grid.width(), grid.height())
+                {
+                    cur_lights.push((light_dir, light_point));
+                }
+            }
+            TileType::Mirror(mirror) => {
+                let new_light_dir = reflect(light_dir, mirror);
+                if let Some(light_point) =
+                    move_light(new_light_dir, light_loc, grid.width(), grid.height())
+                {
+                    cur_lights.push((new_light_dir, light_point));
+                }
+            }
+            TileType::Splitter(splitter) => {
+                let (light_dir1, light_dir2) = split(light_dir, splitter);
+                if let Some(light_point) =
+                    move_light(light_dir1, light_loc, grid.width(), grid.height())
+                {
+                    cur_lights.push((light_dir1, light_point));
+                }
+
+                if let Some(light_dir2) = light_dir2 {
+                    if let Some(light_point) =
+                        move_light(light_dir2, light_loc, grid.width(), grid.height())
+                    {
+                        cur_lights.push((light_dir2, light_point));
+                    }
+                }
+            }
+        }
+    }
+
+    lit_tiles.iter().filter(|(_, lit)| **lit).count() as i64
+}
+
+pub fn solve2(grid: &Grid2D<TileType>) -> i64 {
+    let mut max_illuminated = 0;
+    for (start_dir, start_point) in (0..grid.width())
+        .flat_map(|x| {
+            [
+                (Direction::Down, Point { x, y: 0 }),
+                (
+                    Direction::Up,
+                    Point {
+                        x,
+                        y: grid.height() - 1,
+                    },
+                ),
+            ]
+        })
+        .chain((0..grid.height()).flat_map(|y| {
+            [
+                (
+                    Direction::Left,
+                    Point {
+                        x: grid.width() - 1,
+                        y,
+                    },
+                ),
+                (Direction::Right, Point { x: 0, y }),
+            ]
+        }))
+    {
+        let mut lit_tiles = Grid2D::new(grid.width(), grid.height(), false);
+        let mut cur_lights = vec![(start_dir, start_point)];
+        let mut light_loop_detect = HashSet::new();
+        while let Some((light_dir, light_loc)) = cur_lights.pop() {
+            if !light_loop_detect.insert((light_dir, light_loc)) {
+                continue;
+            }
+
+            lit_tiles[light_loc] = true;
+            match grid[light_loc] {
+                TileType::Empty => {
+                    if let Some(light_point) =
+                        move_light(light_dir, light_loc, grid.width(), grid.height())
+                    {
+                        cur_lights.push((light_dir, light_point));
+                    }
+                }
+                TileType::Mirror(mirror) => {
+                    let new_light_dir = reflect(light_dir, mirror);
+                    if let Some(light_point) =
+                        move_light(new_light_dir, light_loc, grid.width(), grid.height())
+                    {
+                        cur_lights.push((new_light_dir, light_point));
+                    }
+                }
+                TileType::Splitter(splitter) => {
+                    let (light_dir1, light_dir2) = split(light_dir, splitter);
+                    if let Some(light_point) =
+                        move_light(light_dir1, light_loc, grid.width(), grid.height())
+                    {
+                        cur_lights.push((light_dir1, light_point));
+                    }
+
+                    if let Some(light_dir2) = light_dir2 {
+                        if let Some(light_point) =
+                            move_light(light_dir2, light_loc, grid.width(), grid.height())
+                        {
+                            cur_lights.push((light_dir2, light_point));
+                        }
+                    }
+                }
+            }
+        }
+
+        max_illuminated =
+            max_illuminated.max(lit_tiles.iter().filter(|(_, lit)| **lit).count() as i64);
+    }
+
+    max_illuminated
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+    type Parsed<'a> = Grid2D<TileType>;
+    type Params = ();
+    const PARAMS: () = ();
+
+    fn parse(input: &str) -> Result<Grid2D<TileType>, ParseError> {
+        parse(&input.lines().collect::<Vec<_>>())
+    }
+
+    fn part1(grid: &Grid2D<TileType>, _: &()) -> Answer {
+        solve1(grid).into()
+    }
+
+    fn part2(grid: &Grid2D<TileType>, _: &()) -> Answer {
+        solve2(grid).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[&str] = &[
+        r#".|...\...."#,
+        r#"|.-.\....."#,
+        r#".....|-..."#,
+        r#"........|."#,
+        r#".........."#,
+        r#".........\"#,
+        r#"..../.\\.."#,
+        r#".-.-/..|.."#,
+        r#".|....-|.\"#,
+        r#"..//.|...."#,
+    ];
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve1(&parse(INPUT).unwrap()), 46)
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve2(&parse(INPUT).unwrap()), 51)
+    }
+}
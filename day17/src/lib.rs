@@ -0,0 +1,269 @@
+use std::collections::{HashMap, HashSet};
+
+use utils::{parse::ParseError, pathfinding, Answer, Direction, Grid2D, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Node {
+    x: usize,
+    y: usize,
+    last_dir: Direction,
+    last_dir_count: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Edge {
+    node: Node,
+    heat_loss: i64,
+}
+
+pub fn parse(input: &[&str]) -> Result<Grid2D<i64>, ParseError> {
+    let grid = Grid2D::parse(input, "a heat loss digit", |c| {
+        c.to_digit(10).map(|d| d as i64)
+    })?;
+    // the path runs from the top left to the bottom right, so those have to exist
+    if grid.height() == 0 {
+        return Err(ParseError::end_of_input(input.len(), "a heat loss digit"));
+    }
+
+    Ok(grid)
+}
+
+fn min_heat_loss(
+    graph: &HashMap<Node, HashSet<Edge>>,
+    source: Node,
+    target: (usize, usize),
+) -> Option<i64> {
+    pathfinding::dijkstra(
+        source,
+        |node| graph[node].iter().map(|edge| (edge.node, edge.heat_loss)),
+        |node| (node.x, node.y) == target,
+    )
+    .goal_distance()
+}
+
+pub mod part1 {
+    use utils::Point;
+
+    use super::*;
+    use std::collections::HashMap;
+
+    fn build_graph(grid: &Grid2D<i64>) -> HashMap<Node, HashSet<Edge>> {
+        let mut nodes_to_visit = vec![Node {
+            x: 0,
+            y: 0,
+            last_dir: Direction::Right,
+            last_dir_count: 0,
+        }];
+        let mut graph = HashMap::new();
+        while let Some(cur_node) = nodes_to_visit.pop() {
+            graph.insert(cur_node, HashSet::new());
+
+            let last_dir = cur_node.last_dir;
+            for dir in [last_dir.turn_left(), last_dir, last_dir.turn_right()] {
+                if dir == cur_node.last_dir && cur_node.last_dir_count == 3 {
+                    continue;
+                }
+
+                let cur_loc = Point {
+                    x: cur_node.x,
+                    y: cur_node.y,
+                };
+                if let Some(Point { x: new_x, y: new_y }) = dir.step(cur_loc) {
+                    if new_x < grid.width() && new_y < grid.height() {
+                        let dir_count = if dir == cur_node.last_dir {
+                            cur_node.last_dir_count + 1
+                        } else {
+                            1
+                        };
+                        let new_node = Node {
+                            x: new_x,
+                            y: new_y,
+                            last_dir: dir,
+                            last_dir_count: dir_count,
+                        };
+                        let heat_loss = grid[Point { x: new_x, y: new_y }];
+                        graph.get_mut(&cur_node).unwrap().insert(Edge {
+                            node: new_node,
+                            heat_loss,
+                        });
+
+                        if let std::collections::hash_map::Entry::Vacant(e) = graph.entry(new_node)
+                        {
+                            e.insert(HashSet::new());
+                            nodes_to_visit.push(new_node);
+                        }
+                    }
+                }
+            }
+        }
+
+        graph
+    }
+
+    // None when the crucible can't get to the bottom right at all
+    pub fn solve1(grid: &Grid2D<i64>) -> Option<i64> {
+        let graph = build_graph(grid);
+
+        min_heat_loss(
+            &graph,
+            Node {
+                x: 0,
+                y: 0,
+                last_dir: Direction::Right,
+                last_dir_count: 0,
+            },
+            (grid.width() - 1, grid.height() - 1),
+        )
+    }
+}
+
+pub mod part2 {
+    use utils::Point;
+
+    use super::*;
+    use std::collections::HashMap;
+
+    fn build_graph(grid: &Grid2D<i64>) -> HashMap<Node, HashSet<Edge>> {
+        let mut nodes_to_visit = vec![Node {
+            x: 0,
+            y: 0,
+            last_dir: Direction::Right,
+            last_dir_count: 0,
+        }];
+        let mut graph = HashMap::new();
+        while let Some(cur_node) = nodes_to_visit.pop() {
+            graph.insert(cur_node, HashSet::new());
+
+            let last_dir = cur_node.last_dir;
+            for dir in [last_dir.turn_left(), last_dir, last_dir.turn_right()] {
+                if (dir == cur_node.last_dir && cur_node.last_dir_count == 10)
+                    || (dir != cur_node.last_dir && cur_node.last_dir_count < 4)
+                {
+                    continue;
+                }
+
+                let cur_loc = Point {
+                    x: cur_node.x,
+                    y: cur_node.y,
+                };
+                if let Some(Point { x: new_x, y: new_y }) = dir.step(cur_loc) {
+                    if new_x < grid.width() && new_y < grid.height() {
+                        let dir_count = if dir == cur_node.last_dir {
+                            cur_node.last_dir_count + 1
+                        } else {
+                            1
+                        };
+                        let new_node = Node {
+                            x: new_x,
+                            y: new_y,
+                            last_dir: dir,
+                            last_dir_count: dir_count,
+                        };
+
+                        let heat_loss = grid[Point { x: new_x, y: new_y }];
+                        graph.get_mut(&cur_node).unwrap().insert(Edge {
+                            node: new_node,
+                            heat_loss,
+                        });
+
+                        if let std::collections::hash_map::Entry::Vacant(e) = graph.entry(new_node)
+                        {
+                            e.insert(HashSet::new());
+                            nodes_to_visit.push(new_node);
+                        }
+                    }
+                }
+            }
+        }
+
+        graph
+    }
+    // None when the ultra crucible can't get to the bottom right at all, it has to go
+    // 4 blocks before it can turn
+    pub fn solve2(grid: &Grid2D<i64>) -> Option<i64> {
+        let graph = build_graph(grid);
+
+        min_heat_loss(
+            &graph,
+            Node {
+                x: 0,
+                y: 0,
+                last_dir: Direction::Right,
+                last_dir_count: 0,
+            },
+            (grid.width() - 1, grid.height() - 1),
+        )
+    }
+}
+
+const NO_PATH: &str = "no path reaches the bottom right";
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+    type Parsed<'a> = Grid2D<i64>;
+    type Params = ();
+    const PARAMS: () = ();
+
+    fn parse(input: &str) -> Result<Grid2D<i64>, ParseError> {
+        parse(&input.lines().collect::<Vec<_>>())
+    }
+
+    fn part1(grid: &Grid2D<i64>, _: &()) -> Answer {
+        part1::solve1(grid).ok_or(NO_PATH).into()
+    }
+
+    fn part2(grid: &Grid2D<i64>, _: &()) -> Answer {
+        part2::solve2(grid).ok_or(NO_PATH).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[&str] = &[
+        "2413432311323",
+        "3215453535623",
+        "3255245654254",
+        "3446585845452",
+        "4546657867536",
+        "1438598798454",
+        "4457876987766",
+        "3637877979653",
+        "4654967986887",
+        "4564679986453",
+        "1224686865563",
+        "2546548887735",
+        "4322674655533",
+    ];
+
+    #[test]
+    fn test1() {
+        assert_eq!(part1::solve1(&parse(INPUT).unwrap()), Some(102))
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(part2::solve2(&parse(INPUT).unwrap()), Some(94))
+    }
+
+    #[test]
+    fn edge_cases() {
+        let err = parse(&["12", "1"]).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        for empty in [&[][..], &[""], &["", ""]] {
+            let err = parse(empty).unwrap_err();
+            assert_eq!(
+                err,
+                ParseError::end_of_input(empty.len(), "a heat loss digit")
+            );
+        }
+
+        // too small for the ultra crucible to ever turn
+        let grid = parse(&["12", "34"]).unwrap();
+        assert_eq!(part1::solve1(&grid), Some(6));
+        assert_eq!(Day17::part2(&grid, &()), Answer::Error(NO_PATH.to_owned()));
+    }
+}
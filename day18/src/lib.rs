@@ -0,0 +1,136 @@
+use utils::{
+    parse::{self, ParseError},
+    Answer, Direction, IPoint, Solution,
+};
+
+pub fn parse(input: &[&str]) -> Result<Vec<IPoint>, ParseError> {
+    let mut points = vec![IPoint::ORIGIN];
+    let mut cur = IPoint::ORIGIN;
+    for line in parse::lines(input) {
+        let (dir, rest) = line.split_once(line.text, " ")?;
+        let (dist, _) = line.split_once(rest, " ")?;
+        let dist: i64 = line.parse(dist, "a distance")?;
+        let dir = match dir {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            _ => return Err(line.error(dir, "a direction (one of UDLR)")),
+        };
+        cur += dir.delta() * dist;
+        points.push(cur);
+    }
+
+    Ok(points)
+}
+
+// shoelace for the area inside the trench centers, plus the half of the trench outside it
+fn lagoon_size(points: &[IPoint]) -> i64 {
+    let perimeter = points.windows(2).map(|w| w[0].manhattan(w[1])).sum::<i64>();
+
+    points
+        .windows(2)
+        .map(|w| w[0].x * w[1].y - w[1].x * w[0].y)
+        .sum::<i64>()
+        / 2
+        + perimeter / 2
+        + 1
+}
+
+pub fn solve1(points: &[IPoint]) -> i64 {
+    lagoon_size(points)
+}
+
+pub fn parse_part2(input: &[&str]) -> Result<Vec<IPoint>, ParseError> {
+    let mut points = vec![IPoint::ORIGIN];
+    let mut cur = IPoint::ORIGIN;
+    for line in parse::lines(input) {
+        let (_, color) = line.split_once(line.text, "#")?;
+        let (Some(dist), Some(dir)) = (color.get(0..5), color.get(5..6)) else {
+            return Err(line.error(color, "a 6 digit color code"));
+        };
+        let dist = i64::from_str_radix(dist, 16)
+            .map_err(|_| line.error(dist, "a hexadecimal distance"))?;
+        let dir = match dir {
+            "0" => Direction::Right,
+            "1" => Direction::Down,
+            "2" => Direction::Left,
+            "3" => Direction::Up,
+            _ => return Err(line.error(dir, "a direction digit (0 to 3)")),
+        };
+        cur += dir.delta() * dist;
+        points.push(cur);
+    }
+
+    Ok(points)
+}
+
+pub fn solve2(points: &[IPoint]) -> i64 {
+    lagoon_size(points)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+    // the plan is read differently for each part, so keep both sets of corners
+    type Parsed<'a> = (Vec<IPoint>, Vec<IPoint>);
+    type Params = ();
+    const PARAMS: () = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let lines: Vec<&str> = input.lines().collect();
+        Ok((parse(&lines)?, parse_part2(&lines)?))
+    }
+
+    fn part1((points, _): &Self::Parsed<'_>, _: &()) -> Answer {
+        solve1(points).into()
+    }
+
+    fn part2((_, points): &Self::Parsed<'_>, _: &()) -> Answer {
+        solve2(points).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[&str] = &[
+        "R 6 (#70c710)",
+        "D 5 (#0dc571)",
+        "L 2 (#5713f0)",
+        "D 2 (#d2c081)",
+        "R 2 (#59c680)",
+        "D 2 (#411b91)",
+        "L 5 (#8ceee2)",
+        "U 2 (#caa173)",
+        "L 1 (#1b58a2)",
+        "U 2 (#caa171)",
+        "R 2 (#7807d2)",
+        "U 3 (#a77fa3)",
+        "L 2 (#015232)",
+        "U 2 (#7a21e3)",
+    ];
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve1(&parse(INPUT).unwrap()), 62)
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve2(&parse_part2(INPUT).unwrap()), 952408144115)
+    }
+
+    #[test]
+    fn parse_errors() {
+        // other spellings `Direction` accepts aren't part of the plan
+        for dir in ["N", "^", "r"] {
+            let err = parse(&["R 6 (#70c710)", &format!("{dir} 5 (#0dc571)")]).unwrap_err();
+            assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, dir));
+        }
+        let err = parse_part2(&["R 6 (#70c714)"]).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 12, "4"));
+    }
+}
@@ -0,0 +1,372 @@
+use std::collections::HashMap;
+
+use utils::{
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target<'a> {
+    Accept,
+    Reject,
+    NextWorkflow(&'a str),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RatingCategory {
+    ExtremelyCoolLooking,
+    Musical,
+    Aerodynamic,
+    Shiny,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    LessThan,
+    GreaterThan,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Condition {
+    category: RatingCategory,
+    operation: Operation,
+    value: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule<'a> {
+    condition: Option<Condition>,
+    target: Target<'a>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Part {
+    x: usize,
+    m: usize,
+    a: usize,
+    s: usize,
+}
+
+pub type Workflows<'a> = HashMap<&'a str, Vec<Rule<'a>>>;
+
+pub fn parse<'a>(input: &[&'a str]) -> Result<(Workflows<'a>, Vec<Part>), ParseError> {
+    let mut workflows = HashMap::new();
+    let mut parts = Vec::new();
+    let mut references = Vec::new();
+
+    let mut parsing_workflows = true;
+    for line in parse::lines(input) {
+        if parsing_workflows {
+            if line.text.is_empty() {
+                parsing_workflows = false;
+                continue;
+            }
+            let (name, rules_str) = line.split_once(line.text, "{")?;
+            let Some(rules_str) = rules_str.strip_suffix('}') else {
+                return Err(line.char_error(line.text.len(), "\"}\""));
+            };
+            let mut rules = Vec::new();
+            let mut last_rule = rules_str;
+            for rule_txt in rules_str.split(',') {
+                last_rule = rule_txt;
+                let (condition, target) = if let Some((cond_txt, tgt)) = rule_txt.split_once(':') {
+                    let category = match cond_txt.get(0..1) {
+                        Some("x") => RatingCategory::ExtremelyCoolLooking,
+                        Some("m") => RatingCategory::Musical,
+                        Some("a") => RatingCategory::Aerodynamic,
+                        Some("s") => RatingCategory::Shiny,
+                        _ => return Err(line.error(cond_txt, "a category (one of xmas)")),
+                    };
+                    let operation = match cond_txt.get(1..2) {
+                        Some("<") => Operation::LessThan,
+                        Some(">") => Operation::GreaterThan,
+                        _ => return Err(line.error(&cond_txt[1..], "< or >")),
+                    };
+
+                    let value = line.parse(&cond_txt[2..], "a rating")?;
+                    (
+                        Some(Condition {
+                            category,
+                            operation,
+                            value,
+                        }),
+                        tgt,
+                    )
+                } else {
+                    // only target, no condition
+                    (None, rule_txt)
+                };
+
+                let target = match target {
+                    "A" => Target::Accept,
+                    "R" => Target::Reject,
+                    _ => {
+                        references.push((line, target));
+                        Target::NextWorkflow(target)
+                    }
+                };
+
+                rules.push(Rule { condition, target });
+            }
+            // every part has to leave the workflow somewhere
+            if rules.last().is_some_and(|rule| rule.condition.is_some()) {
+                return Err(line.error(last_rule, "a last rule without a condition"));
+            }
+            workflows.insert(name, rules);
+        } else {
+            let rest = line
+                .text
+                .strip_prefix("{x=")
+                .ok_or_else(|| line.error(line.text, "\"{x=\""))?;
+            let (x, rest) = line.split_once(rest, ",m=")?;
+            let x = line.parse(x, "a rating")?;
+            let (m, rest) = line.split_once(rest, ",a=")?;
+            let m = line.parse(m, "a rating")?;
+            let (a, rest) = line.split_once(rest, ",s=")?;
+            let a = line.parse(a, "a rating")?;
+            let (s, _) = line.split_once(rest, "}")?;
+            let s = line.parse(s, "a rating")?;
+
+            parts.push(Part { x, m, a, s });
+        }
+    }
+
+    for (line, name) in references {
+        if !workflows.contains_key(name) {
+            return Err(line.error(name, "the name of a workflow"));
+        }
+    }
+    if !workflows.contains_key("in") {
+        return Err(ParseError::end_of_input(input.len(), "a workflow named in"));
+    }
+
+    Ok((workflows, parts))
+}
+
+pub fn solve1(workflows: &Workflows, parts: &[Part]) -> usize {
+    let mut total = 0;
+
+    for part in parts {
+        let mut cur_workflow = workflows.get("in").unwrap().iter();
+        loop {
+            // parse makes every workflow end in a rule without a condition
+            let rule = cur_workflow.next().unwrap();
+            let send_to_target = if let Some(condition) = rule.condition {
+                // evaluate rule
+                let test_val = match condition.category {
+                    RatingCategory::ExtremelyCoolLooking => part.x,
+                    RatingCategory::Musical => part.m,
+                    RatingCategory::Aerodynamic => part.a,
+                    RatingCategory::Shiny => part.s,
+                };
+                match condition.operation {
+                    Operation::LessThan => test_val < condition.value,
+                    Operation::GreaterThan => test_val > condition.value,
+                }
+            } else {
+                true
+            };
+
+            if send_to_target {
+                match rule.target {
+                    Target::Accept => {
+                        total += part.x + part.m + part.a + part.s;
+                        break;
+                    }
+                    Target::Reject => break,
+                    Target::NextWorkflow(tgt_workflow) => {
+                        cur_workflow = workflows.get(tgt_workflow).unwrap().iter();
+                    }
+                }
+            }
+        }
+    }
+
+    total
+}
+
+#[derive(Debug, Clone, Copy)]
+struct XmasState {
+    x_bounds: (usize, usize),
+    m_bounds: (usize, usize),
+    a_bounds: (usize, usize),
+    s_bounds: (usize, usize),
+}
+
+fn resolve_accept_inputs(
+    workflows: &Workflows,
+    cur_workflow: Target,
+    rule_offset: usize,
+    total_accept_parts: &mut usize,
+    xmas_state: XmasState,
+) {
+    match cur_workflow {
+        Target::Accept => {
+            *total_accept_parts += (xmas_state.x_bounds.1 + 1 - xmas_state.x_bounds.0)
+                * (xmas_state.m_bounds.1 + 1 - xmas_state.m_bounds.0)
+                * (xmas_state.a_bounds.1 + 1 - xmas_state.a_bounds.0)
+                * (xmas_state.s_bounds.1 + 1 - xmas_state.s_bounds.0);
+        }
+        Target::Reject => (),
+        Target::NextWorkflow(workflow_id) => {
+            let workflow = workflows.get(workflow_id).unwrap();
+            let rule = workflow[rule_offset];
+
+            if let Some(condition) = rule.condition {
+                // variant: we observe this rule
+                if !matches!(rule.target, Target::Reject) {
+                    let mut split_xmas_state = xmas_state;
+                    let range_to_mod = match condition.category {
+                        RatingCategory::ExtremelyCoolLooking => &mut split_xmas_state.x_bounds,
+                        RatingCategory::Musical => &mut split_xmas_state.m_bounds,
+                        RatingCategory::Aerodynamic => &mut split_xmas_state.a_bounds,
+                        RatingCategory::Shiny => &mut split_xmas_state.s_bounds,
+                    };
+                    match condition.operation {
+                        Operation::LessThan => {
+                            range_to_mod.1 = range_to_mod.1.min(condition.value - 1)
+                        }
+                        Operation::GreaterThan => {
+                            range_to_mod.0 = range_to_mod.0.max(condition.value + 1)
+                        }
+                    }
+
+                    // make sure this range is still possible
+                    if range_to_mod.0 <= range_to_mod.1 {
+                        resolve_accept_inputs(
+                            workflows,
+                            rule.target,
+                            0,
+                            total_accept_parts,
+                            split_xmas_state,
+                        );
+                    }
+                }
+
+                // variant: we DO NOT observe this rule
+                let mut split_xmas_state = xmas_state;
+                let range_to_mod = match condition.category {
+                    RatingCategory::ExtremelyCoolLooking => &mut split_xmas_state.x_bounds,
+                    RatingCategory::Musical => &mut split_xmas_state.m_bounds,
+                    RatingCategory::Aerodynamic => &mut split_xmas_state.a_bounds,
+                    RatingCategory::Shiny => &mut split_xmas_state.s_bounds,
+                };
+
+                // do negated operation
+                match condition.operation {
+                    Operation::LessThan => range_to_mod.0 = range_to_mod.0.max(condition.value),
+                    Operation::GreaterThan => range_to_mod.1 = range_to_mod.1.min(condition.value),
+                }
+
+                // make sure this range is still possible
+                if range_to_mod.0 <= range_to_mod.1 {
+                    resolve_accept_inputs(
+                        workflows,
+                        cur_workflow,
+                        rule_offset + 1,
+                        total_accept_parts,
+                        split_xmas_state,
+                    );
+                }
+            } else {
+                resolve_accept_inputs(workflows, rule.target, 0, total_accept_parts, xmas_state);
+            }
+        }
+    }
+}
+
+pub fn solve2(workflows: &Workflows) -> usize {
+    let mut total_accept_parts = 0;
+
+    resolve_accept_inputs(
+        workflows,
+        Target::NextWorkflow("in"),
+        0,
+        &mut total_accept_parts,
+        XmasState {
+            x_bounds: (1, 4000),
+            m_bounds: (1, 4000),
+            a_bounds: (1, 4000),
+            s_bounds: (1, 4000),
+        },
+    );
+
+    total_accept_parts
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+    type Parsed<'a> = (Workflows<'a>, Vec<Part>);
+    type Params = ();
+    const PARAMS: () = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse(&input.lines().collect::<Vec<_>>())
+    }
+
+    fn part1((workflows, parts): &Self::Parsed<'_>, _: &()) -> Answer {
+        solve1(workflows, parts).into()
+    }
+
+    fn part2((workflows, _): &Self::Parsed<'_>, _: &()) -> Answer {
+        solve2(workflows).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[&str] = &[
+        "px{a<2006:qkq,m>2090:A,rfg}",
+        "pv{a>1716:R,A}",
+        "lnx{m>1548:A,A}",
+        "rfg{s<537:gd,x>2440:R,A}",
+        "qs{s>3448:A,lnx}",
+        "qkq{x<1416:A,crn}",
+        "crn{x>2662:A,R}",
+        "in{s<1351:px,qqz}",
+        "qqz{s>2770:qs,m<1801:hdj,R}",
+        "gd{a>3333:R,R}",
+        "hdj{m>838:A,pv}",
+        "",
+        "{x=787,m=2655,a=1222,s=2876}",
+        "{x=1679,m=44,a=2067,s=496}",
+        "{x=2036,m=264,a=79,s=2244}",
+        "{x=2461,m=1339,a=466,s=291}",
+        "{x=2127,m=1623,a=2188,s=1013}",
+    ];
+
+    #[test]
+    fn parse_errors() {
+        let err = parse(&["in{a<2006:qkq,R}", "qkq{q>5:A,R}"]).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 5, "q>5"));
+
+        let err = parse(&["in{a<2006:qkq,R}"]).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 11, "qkq"));
+
+        let err = parse(&["in{A}", "", "{x=787,m=2655,a=1222}"]).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (3, 17, "\",s=\"")
+        );
+
+        let err = parse(&["in{a<2006:A,s>5:R}"]).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 13, "s>5:R"));
+        assert_eq!(err.expected, "a last rule without a condition");
+    }
+
+    #[test]
+    fn test1() {
+        let (workflows, parts) = parse(INPUT).unwrap();
+        assert_eq!(solve1(&workflows, &parts), 19114)
+    }
+
+    #[test]
+    fn test2() {
+        let (workflows, _) = parse(INPUT).unwrap();
+        assert_eq!(solve2(&workflows), 167409079868000)
+    }
+}
@@ -0,0 +1,398 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt,
+};
+
+use utils::{
+    math,
+    parse::{self, ParseError},
+    Answer, Solution,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum PulseLevel {
+    Low,
+    High,
+}
+
+#[derive(Debug)]
+struct AddressedPulse {
+    level: PulseLevel,
+    sender: String,
+    destination: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct FlipFlop {
+    on: bool,
+    outputs: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Conjunction {
+    inputs: HashMap<String, PulseLevel>,
+    outputs: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Broadcast {
+    outputs: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Module {
+    FlipFlop(FlipFlop),
+    Conjunction(Conjunction),
+    Broadcast(Broadcast),
+}
+
+impl Module {
+    fn process_pulse(&mut self, pulse: AddressedPulse) -> Vec<AddressedPulse> {
+        let mut out_pulses = Vec::new();
+
+        match self {
+            Module::FlipFlop(flipflop) => match pulse.level {
+                PulseLevel::Low => {
+                    let out_pulse_level = if flipflop.on {
+                        flipflop.on = false;
+                        PulseLevel::Low
+                    } else {
+                        flipflop.on = true;
+                        PulseLevel::High
+                    };
+
+                    for out in flipflop.outputs.iter() {
+                        out_pulses.push(AddressedPulse {
+                            level: out_pulse_level,
+                            sender: pulse.destination.clone(),
+                            destination: out.clone(),
+                        });
+                    }
+                }
+                PulseLevel::High => (), // do nothing
+            },
+            Module::Conjunction(conjunction) => {
+                *conjunction.inputs.get_mut(&pulse.sender).unwrap() = pulse.level;
+                let mut send_low = true;
+                for val in conjunction.inputs.values() {
+                    if matches!(*val, PulseLevel::Low) {
+                        send_low = false;
+                        break;
+                    }
+                }
+                let out_pulse_level = if send_low {
+                    PulseLevel::Low
+                } else {
+                    PulseLevel::High
+                };
+                for out in conjunction.outputs.iter() {
+                    out_pulses.push(AddressedPulse {
+                        level: out_pulse_level,
+                        sender: pulse.destination.clone(),
+                        destination: out.clone(),
+                    });
+                }
+            }
+            Module::Broadcast(broadcaster) => {
+                for out in broadcaster.outputs.iter() {
+                    out_pulses.push(AddressedPulse {
+                        level: pulse.level,
+                        sender: "broadcaster".to_owned(),
+                        destination: out.clone(),
+                    });
+                }
+            }
+        }
+
+        out_pulses
+    }
+}
+
+pub fn parse(input: &[&str]) -> Result<HashMap<String, Module>, ParseError> {
+    let mut modules = HashMap::new();
+    let mut conjunctions = HashSet::new();
+    for line in parse::lines(input) {
+        let (left, right) = line.split_once(line.text, " -> ")?;
+        let outputs = right.split(", ").map(|s| s.to_owned()).collect();
+        let (id, module) = if let Some(id) = left.strip_prefix('%') {
+            // flip flop
+            (id, Module::FlipFlop(FlipFlop { on: false, outputs }))
+        } else if let Some(id) = left.strip_prefix('&') {
+            // conjunction
+            conjunctions.insert(id);
+            (
+                id,
+                Module::Conjunction(Conjunction {
+                    inputs: HashMap::new(),
+                    outputs,
+                }),
+            )
+        } else if left == "broadcaster" {
+            (left, Module::Broadcast(Broadcast { outputs }))
+        } else {
+            return Err(line.error(left, "a flip-flop, conjunction or the broadcaster"));
+        };
+
+        if modules.insert(id.to_owned(), module).is_some() {
+            return Err(line.error(id, "a module name that isn't already taken"));
+        }
+    }
+
+    let mut conjuction_inputs: HashMap<String, HashSet<String>> = HashMap::new();
+    for (module_id, module) in modules.iter() {
+        match module {
+            Module::FlipFlop(ff) => {
+                for out in &ff.outputs {
+                    if conjunctions.contains(out.as_str()) {
+                        conjuction_inputs
+                            .entry(out.clone())
+                            .or_default()
+                            .insert(module_id.clone());
+                    }
+                }
+            }
+            Module::Conjunction(c) => {
+                for out in &c.outputs {
+                    if conjunctions.contains(out.as_str()) {
+                        conjuction_inputs
+                            .entry(out.clone())
+                            .or_default()
+                            .insert(module_id.clone());
+                    }
+                }
+            }
+            Module::Broadcast(b) => {
+                for out in &b.outputs {
+                    if conjunctions.contains(out.as_str()) {
+                        conjuction_inputs
+                            .entry(out.clone())
+                            .or_default()
+                            .insert(module_id.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    // names are unique, so everything in `conjunctions` is still a conjunction
+    for (conjunction_id, inputs) in conjuction_inputs {
+        if let Some(Module::Conjunction(conj)) = modules.get_mut(&conjunction_id) {
+            for input in inputs {
+                conj.inputs.insert(input, PulseLevel::Low);
+            }
+        }
+    }
+
+    Ok(modules)
+}
+
+pub fn solve1(modules: &HashMap<String, Module>) -> i64 {
+    let mut modules = modules.clone();
+    let mut pulses = VecDeque::new();
+    let mut num_high_pulses = 0;
+    let mut num_low_pulses = 0;
+
+    for _ in 0..1000 {
+        pulses.push_back(AddressedPulse {
+            level: PulseLevel::Low,
+            sender: "button".to_owned(),
+            destination: "broadcaster".to_owned(),
+        });
+
+        while let Some(pulse) = pulses.pop_front() {
+            match pulse.level {
+                PulseLevel::Low => num_low_pulses += 1,
+                PulseLevel::High => num_high_pulses += 1,
+            }
+            if let Some(module) = modules.get_mut(&pulse.destination) {
+                let new_pulses = module.process_pulse(pulse);
+                for new_pulse in new_pulses {
+                    pulses.push_back(new_pulse);
+                }
+            }
+        }
+    }
+
+    num_high_pulses * num_low_pulses
+}
+
+// presses part 2 waits for every input of rx's feeder to send a high pulse. The
+// real inputs are 12 bit counters, so they all have well before this
+pub const PRESS_LIMIT: u64 = 1 << 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RxError {
+    // part 2 relies on rx being fed by a single conjunction, which has inputs
+    NoFeeder,
+    // some input of the feeder sent no high pulse within `PRESS_LIMIT` presses
+    NoHighPulse,
+    // the presses for every input of the feeder to line up don't fit in an i64
+    Overflow,
+}
+
+impl fmt::Display for RxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RxError::NoFeeder => write!(f, "rx isn't fed by a single conjunction with inputs"),
+            RxError::NoHighPulse => write!(
+                f,
+                "an input of the conjunction feeding rx sent no high pulse in {PRESS_LIMIT} presses"
+            ),
+            RxError::Overflow => write!(f, "the button press count doesn't fit in an i64"),
+        }
+    }
+}
+
+impl std::error::Error for RxError {}
+
+fn outputs(module: &Module) -> &[String] {
+    match module {
+        Module::FlipFlop(ff) => &ff.outputs,
+        Module::Conjunction(c) => &c.outputs,
+        Module::Broadcast(b) => &b.outputs,
+    }
+}
+
+// rx gets a low pulse once every input of the conjunction feeding it has sent a high
+// one on the same press. Each input does so periodically, so that's the lcm of the
+// first press each one does
+pub fn solve2(modules: &HashMap<String, Module>) -> Result<i64, RxError> {
+    let mut feeders = modules
+        .iter()
+        .filter(|(_, module)| outputs(module).iter().any(|out| out == "rx"));
+    let (Some((feeder_id, Module::Conjunction(feeder))), None) = (feeders.next(), feeders.next())
+    else {
+        return Err(RxError::NoFeeder);
+    };
+    if feeder.inputs.is_empty() {
+        return Err(RxError::NoFeeder);
+    }
+
+    let mut modules = modules.clone();
+    let mut pulses = VecDeque::new();
+    let mut seen_inputs: HashMap<String, u64> = feeder
+        .inputs
+        .keys()
+        .map(|input| (input.clone(), 0))
+        .collect();
+    for num_button_presses in 1..=PRESS_LIMIT {
+        pulses.push_back(AddressedPulse {
+            level: PulseLevel::Low,
+            sender: "button".to_owned(),
+            destination: "broadcaster".to_owned(),
+        });
+
+        while let Some(pulse) = pulses.pop_front() {
+            if &pulse.destination == feeder_id && matches!(pulse.level, PulseLevel::High) {
+                let seen = seen_inputs.get_mut(&pulse.sender).unwrap();
+                if *seen == 0 {
+                    *seen = num_button_presses;
+                    if seen_inputs.values().all(|v| *v != 0) {
+                        return math::lcm_all(seen_inputs.values().copied())
+                            .and_then(|presses| i64::try_from(presses).ok())
+                            .ok_or(RxError::Overflow);
+                    }
+                }
+            }
+            if let Some(module) = modules.get_mut(&pulse.destination) {
+                let new_pulses = module.process_pulse(pulse);
+                for new_pulse in new_pulses {
+                    pulses.push_back(new_pulse);
+                }
+            }
+        }
+    }
+
+    Err(RxError::NoHighPulse)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u32 = 20;
+    type Parsed<'a> = HashMap<String, Module>;
+    type Params = ();
+    const PARAMS: () = ();
+
+    fn parse(input: &str) -> Result<HashMap<String, Module>, ParseError> {
+        parse(&input.lines().collect::<Vec<_>>())
+    }
+
+    fn part1(modules: &HashMap<String, Module>, _: &()) -> Answer {
+        solve1(modules).into()
+    }
+
+    fn part2(modules: &HashMap<String, Module>, _: &()) -> Answer {
+        solve2(modules).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT1: &[&str] = &[
+        "broadcaster -> a, b, c",
+        "%a -> b",
+        "%b -> c",
+        "%c -> inv",
+        "&inv -> a",
+    ];
+    const INPUT2: &[&str] = &[
+        "broadcaster -> a",
+        "%a -> inv, con",
+        "&inv -> b",
+        "%b -> con",
+        "&con -> output",
+    ];
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve1(&parse(INPUT1).unwrap()), 32000000);
+        assert_eq!(solve1(&parse(INPUT2).unwrap()), 11687500)
+    }
+
+    #[test]
+    fn no_feeder() {
+        let modules = parse(INPUT2).unwrap();
+        assert_eq!(solve2(&modules), Err(RxError::NoFeeder));
+        assert_eq!(
+            Day20::part2(&modules, &()),
+            Answer::Error(RxError::NoFeeder.to_string())
+        );
+        let modules = parse(&["broadcaster -> a, b", "&a -> rx", "&b -> rx"]).unwrap();
+        assert_eq!(solve2(&modules), Err(RxError::NoFeeder));
+    }
+
+    #[test]
+    fn feeder_by_any_name() {
+        // con first hears high from a on press 1 and from b on press 2
+        let modules = parse(&[
+            "broadcaster -> a",
+            "%a -> con, b",
+            "%b -> con",
+            "&con -> rx",
+        ])
+        .unwrap();
+        assert_eq!(solve2(&modules), Ok(2));
+    }
+
+    #[test]
+    fn no_high_pulse() {
+        // inv only ever sends f high pulses, which f ignores
+        let modules =
+            parse(&["broadcaster -> inv", "&inv -> f", "%f -> con", "&con -> rx"]).unwrap();
+        assert_eq!(solve2(&modules), Err(RxError::NoHighPulse));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = parse(&["broadcaster -> x", "&x -> a", "%x -> b"]).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 2, "x"));
+        assert_eq!(err.expected, "a module name that isn't already taken");
+        let err = parse(&["broadcaster -> a", "broadcaster -> b"]).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse(&["broadcaster -> a", "a -> b"]).unwrap_err();
+        assert_eq!(err.expected, "a flip-flop, conjunction or the broadcaster");
+    }
+}
@@ -0,0 +1,223 @@
+use std::collections::{HashMap, HashSet};
+
+use utils::{parse::ParseError, pathfinding, Answer, Grid2D, IPoint, Point, Solution};
+
+pub fn parse(input: &[&str]) -> Result<(Point, Grid2D<bool>), ParseError> {
+    let tiles = Grid2D::parse(input, "a garden plot, rock or the start", |chr| {
+        matches!(chr, '.' | '#' | 'S').then_some(chr)
+    })?;
+    let (start, _) = tiles
+        .iter()
+        .find(|(_, chr)| **chr == 'S')
+        .ok_or_else(|| ParseError::end_of_input(input.len(), "a start tile"))?;
+
+    Ok((start, tiles.map(|chr| *chr == '#')))
+}
+
+fn traverse(
+    grid: &Grid2D<bool>,
+    steps_remaining: usize,
+    cur_location: Point,
+    end_location_map: &mut HashSet<Point>,
+    memory: &mut HashSet<(Point, usize)>,
+) {
+    if steps_remaining == 0 {
+        end_location_map.insert(cur_location);
+    } else {
+        for next_pos in grid.limited_adjacents(cur_location) {
+            let next_loc = Point {
+                x: next_pos.x,
+                y: next_pos.y,
+            };
+            if !*next_pos.val && !memory.contains(&(next_loc, steps_remaining - 1)) {
+                // is ground and new
+                traverse(
+                    grid,
+                    steps_remaining - 1,
+                    next_loc,
+                    end_location_map,
+                    memory,
+                );
+                memory.insert((next_loc, steps_remaining - 1));
+            }
+        }
+    }
+}
+
+pub fn solve1(start: Point, grid: &Grid2D<bool>, num_steps: usize) -> usize {
+    let mut end_location_map = HashSet::new();
+    let mut memory = HashSet::new();
+    traverse(grid, num_steps, start, &mut end_location_map, &mut memory);
+
+    end_location_map.len()
+}
+
+// steps from the source to every plot reachable without leaving `area`, with the
+// garden repeating in every direction past its edges
+fn step_distances(
+    grid: &Grid2D<bool>,
+    source: IPoint,
+    area: impl Fn(IPoint) -> bool,
+) -> HashMap<IPoint, usize> {
+    let (width, height) = (grid.width() as i64, grid.height() as i64);
+    let is_rock = |p: IPoint| {
+        let tile = p.rem_euclid(width, height);
+        grid[Point {
+            x: tile.x as usize,
+            y: tile.y as usize,
+        }]
+    };
+    pathfinding::bfs(
+        source,
+        |loc| {
+            loc.neighbours()
+                .filter(|next| area(*next) && !is_rock(*next))
+                .collect::<Vec<_>>()
+        },
+        |_| false,
+    )
+    .distances
+}
+
+// plots with the same parity as `num_steps` and no further away, stepping back and
+// forth fills in the rest
+fn reachable(distances: impl Iterator<Item = usize>, num_steps: usize) -> usize {
+    distances
+        .filter(|d| *d <= num_steps && d % 2 == num_steps % 2)
+        .count()
+}
+
+// walks the tiled garden directly, only feasible for smaller step counts
+fn solve_on_plane(start: IPoint, grid: &Grid2D<bool>, num_steps: usize) -> usize {
+    let within = |p: IPoint| p.manhattan(start) <= num_steps as i64;
+    reachable(step_distances(grid, start, within).into_values(), num_steps)
+}
+
+// the real input is a square of odd size with the start in the middle, and its middle row
+// and column and its edges are clear. Then every tile is entered from a corner or the
+// middle of an edge, and a step count that ends on the edge of a tile reaches a diamond of
+// whole tiles with the corners of the outermost ones cut off
+fn is_tileable(start: IPoint, grid: &Grid2D<bool>, num_steps: usize) -> bool {
+    let size = grid.width();
+    let half = size / 2;
+    let clear = |fixed: usize| {
+        (0..size).all(|i| !grid[Point { x: fixed, y: i }] && !grid[Point { x: i, y: fixed }])
+    };
+
+    size % 2 == 1
+        && grid.height() == size
+        && start == IPoint::new(half as i64, half as i64)
+        && num_steps >= half
+        && (num_steps - half).is_multiple_of(size)
+        && [0, half, size - 1].into_iter().all(clear)
+}
+
+pub fn solve2(start: Point, grid: &Grid2D<bool>, num_steps: usize) -> usize {
+    let start = IPoint::from(start);
+    if !is_tileable(start, grid, num_steps) {
+        return solve_on_plane(start, grid, num_steps);
+    }
+
+    let size = grid.width();
+    let tile = |p: IPoint| (0..size as i64).contains(&p.x) && (0..size as i64).contains(&p.y);
+    let distances = step_distances(grid, start, tile);
+
+    // n whole tiles out from the centre, the ring n tiles out gets its corners cut off
+    // and the ring past it only gets those corners
+    let half = size / 2;
+    let n = (num_steps - half) / size;
+    // the size is odd, so every other ring reaches plots of the opposite parity
+    let outer_parity = (num_steps + n) % 2;
+    let count = |parity: usize, corners_only: bool| {
+        distances
+            .values()
+            .filter(|d| **d % 2 == parity && (!corners_only || **d > half))
+            .count()
+    };
+    let (outer_full, inner_full) = (count(outer_parity, false), count(1 - outer_parity, false));
+    let (outer_corners, inner_corners) = (count(outer_parity, true), count(1 - outer_parity, true));
+
+    (n + 1) * (n + 1) * outer_full + n * n * inner_full - (n + 1) * outer_corners
+        + n * inner_corners
+}
+
+pub struct Params {
+    pub part1_steps: usize,
+    pub part2_steps: usize,
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u32 = 21;
+    type Parsed<'a> = (Point, Grid2D<bool>);
+    type Params = Params;
+    const PARAMS: Params = Params {
+        part1_steps: 64,
+        part2_steps: 26501365,
+    };
+
+    fn parse(input: &str) -> Result<(Point, Grid2D<bool>), ParseError> {
+        parse(&input.lines().collect::<Vec<_>>())
+    }
+
+    fn part1((start, grid): &(Point, Grid2D<bool>), params: &Params) -> Answer {
+        solve1(*start, grid, params.part1_steps).into()
+    }
+
+    fn part2((start, grid): &(Point, Grid2D<bool>), params: &Params) -> Answer {
+        solve2(*start, grid, params.part2_steps).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[&str] = &[
+        "...........",
+        ".....###.#.",
+        ".###.##..#.",
+        "..#.#...#..",
+        "....#.#....",
+        ".##..S####.",
+        ".##..#...#.",
+        ".......##..",
+        ".##.#.####.",
+        ".##..##.##.",
+        "...........",
+    ];
+
+    #[test]
+    fn test1() {
+        let (start, grid) = parse(INPUT).unwrap();
+        assert_eq!(solve1(start, &grid, 6), 16)
+    }
+
+    #[test]
+    fn test2() {
+        let (start, grid) = parse(INPUT).unwrap();
+        for (steps, plots) in [(6, 16), (10, 50), (50, 1594), (100, 6536)] {
+            assert_eq!(solve2(start, &grid, steps), plots, "{steps} steps");
+        }
+    }
+
+    #[test]
+    fn tiling_matches_plane() {
+        let (start, grid) = parse(&[
+            ".......", ".#...#.", "..#.##.", "...S...", ".##.#..", "..#..#.", ".......",
+        ])
+        .unwrap();
+        let start = IPoint::from(start);
+        for steps in [3, 10, 17, 24, 31, 38] {
+            assert!(is_tileable(start, &grid, steps));
+            assert_eq!(
+                solve2(Point { x: 3, y: 3 }, &grid, steps),
+                solve_on_plane(start, &grid, steps),
+                "{steps} steps"
+            );
+        }
+        assert!(!is_tileable(start, &grid, 2));
+        assert_eq!(solve2(Point { x: 3, y: 3 }, &grid, 2), 5);
+    }
+}
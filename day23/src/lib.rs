@@ -0,0 +1,302 @@
+use utils::{
+    parse::{self, ParseError},
+    Answer, Direction, Grid2D, Point, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Path,
+    Forest,
+    Slope(Direction),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trails {
+    pub grid: Grid2D<Tile>,
+    // the path tiles in the first and last row
+    pub start: Point,
+    pub end: Point,
+}
+
+pub fn parse(input: &[&str]) -> Result<Trails, ParseError> {
+    let grid = Grid2D::parse(input, "a path, forest or slope", |c| match c {
+        '.' => Some(Tile::Path),
+        '#' => Some(Tile::Forest),
+        '^' | '>' | 'v' | '<' => Direction::try_from(c).ok().map(Tile::Slope),
+        _ => None,
+    })?;
+
+    // every char is a single byte once the grid parsed, so byte indices are columns
+    for line in parse::lines(input) {
+        for x in 0..grid.width() {
+            let point = Point { x, y: line.index };
+            let Tile::Slope(slope) = grid[point] else {
+                continue;
+            };
+            if !slope.step(point).is_some_and(|p| grid.contains(p)) {
+                return Err(line.char_error(x, "a slope that stays on the map"));
+            }
+        }
+    }
+
+    let path_in_row = |y| {
+        (0..grid.width())
+            .map(|x| Point { x, y })
+            .find(|p| grid[*p] == Tile::Path)
+    };
+    let start =
+        path_in_row(0).ok_or_else(|| ParseError::new(1, 1, "", "a path tile in the first row"))?;
+    let end = grid
+        .height()
+        .checked_sub(1)
+        .and_then(path_in_row)
+        .ok_or_else(|| ParseError::new(input.len().max(1), 1, "", "a path tile in the last row"))?;
+
+    Ok(Trails { grid, start, end })
+}
+
+pub mod part1 {
+    use std::collections::HashMap;
+
+    use utils::Point;
+
+    use crate::{Tile, Trails};
+
+    fn graph(trails: &Trails) -> HashMap<Point, Vec<Point>> {
+        let grid = &trails.grid;
+        let mut map = HashMap::new();
+        for y in 0..grid.height() {
+            for x in 0..grid.width() {
+                let cur_loc = Point { x, y };
+                let targets = match grid[cur_loc] {
+                    Tile::Forest => Vec::new(),
+                    // parsing makes sure slopes don't point off the map
+                    Tile::Slope(slope) => slope.step(cur_loc).into_iter().collect(),
+                    Tile::Path => grid
+                        .limited_adjacents(cur_loc)
+                        .map(|adj| (Point { x: adj.x, y: adj.y }, *adj.val))
+                        .filter(|&(target_loc, tile)| match tile {
+                            Tile::Path => true,
+                            Tile::Forest => false,
+                            // slopes can only be entered going their way
+                            Tile::Slope(slope) => slope.step(cur_loc) == Some(target_loc),
+                        })
+                        .map(|(target_loc, _)| target_loc)
+                        .collect(),
+                };
+                map.insert(cur_loc, targets);
+            }
+        }
+
+        map
+    }
+
+    fn longest_path(
+        map: &HashMap<Point, Vec<Point>>,
+        end: Point,
+        path: &mut Vec<Point>,
+        max_path_len: &mut usize,
+    ) {
+        let cur_loc = path.last().unwrap();
+        if *cur_loc == end {
+            *max_path_len = (*max_path_len).max(path.len());
+        } else {
+            for target_loc in map.get(cur_loc).unwrap() {
+                if !path.contains(target_loc) {
+                    path.push(*target_loc);
+                    longest_path(map, end, path, max_path_len);
+                    path.pop();
+                }
+            }
+        }
+    }
+
+    pub fn solve1(trails: &Trails) -> usize {
+        let map = graph(trails);
+        let mut max_path_len = 0;
+        let mut path = vec![trails.start];
+        longest_path(&map, trails.end, &mut path, &mut max_path_len);
+
+        max_path_len - 1
+    }
+}
+
+pub mod part2 {
+    use std::collections::HashMap;
+
+    use utils::Point;
+
+    use crate::{Tile, Trails};
+
+    // slopes are just paths here
+    fn graph(trails: &Trails) -> HashMap<Point, Vec<Point>> {
+        let grid = &trails.grid;
+        let mut map = HashMap::new();
+        for y in 0..grid.height() {
+            for x in 0..grid.width() {
+                let cur_loc = Point { x, y };
+                let targets = match grid[cur_loc] {
+                    Tile::Forest => Vec::new(),
+                    Tile::Path | Tile::Slope(_) => grid
+                        .limited_adjacents(cur_loc)
+                        .filter(|adj| *adj.val != Tile::Forest)
+                        .map(|adj| Point { x: adj.x, y: adj.y })
+                        .collect(),
+                };
+                map.insert(cur_loc, targets);
+            }
+        }
+
+        map
+    }
+
+    fn simplify_map(
+        map: &HashMap<Point, Vec<Point>>,
+        start: Point,
+        end: Point,
+    ) -> HashMap<Point, Vec<(Point, usize)>> {
+        let mut small_map = HashMap::new();
+
+        for junction_point in map
+            .iter()
+            .filter(|(_, v)| v.len() >= 3)
+            .map(|(p, _)| *p)
+            .chain([start, end])
+        {
+            // find which junction points this connects to
+            for dir_choice in map.get(&junction_point).unwrap() {
+                let mut prev_loc = junction_point;
+                let mut cur_loc = *dir_choice;
+                let mut dist = 1;
+                while map.get(&cur_loc).unwrap().len() < 3 && cur_loc != start && cur_loc != end {
+                    let tmp = cur_loc;
+                    cur_loc = *map
+                        .get(&cur_loc)
+                        .unwrap()
+                        .iter()
+                        .find(|p| **p != prev_loc)
+                        .unwrap();
+                    prev_loc = tmp;
+                    dist += 1;
+                }
+
+                small_map
+                    .entry(junction_point)
+                    .or_insert(Vec::new())
+                    .push((cur_loc, dist));
+            }
+        }
+
+        small_map
+    }
+
+    fn longest_path(
+        map: &HashMap<Point, Vec<(Point, usize)>>,
+        end: Point,
+        path: &mut Vec<Point>,
+        dist: usize,
+        max_path_len: &mut usize,
+    ) {
+        let cur_loc = path.last().unwrap();
+        if *cur_loc == end {
+            *max_path_len = (*max_path_len).max(dist);
+        } else {
+            for (target_loc, tgt_dist) in map.get(cur_loc).unwrap() {
+                if !path.contains(target_loc) {
+                    path.push(*target_loc);
+                    longest_path(map, end, path, dist + tgt_dist, max_path_len);
+                    path.pop();
+                }
+            }
+        }
+    }
+
+    pub fn solve2(trails: &Trails) -> usize {
+        let map = simplify_map(&graph(trails), trails.start, trails.end);
+        let mut max_path_len = 0;
+        let mut path = vec![trails.start];
+        longest_path(&map, trails.end, &mut path, 0, &mut max_path_len);
+
+        max_path_len
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u32 = 23;
+    // slopes mean different things in each part, so each builds its own graph
+    type Parsed<'a> = Trails;
+    type Params = ();
+    const PARAMS: () = ();
+
+    fn parse(input: &str) -> Result<Trails, ParseError> {
+        parse(&input.lines().collect::<Vec<_>>())
+    }
+
+    fn part1(trails: &Trails, _: &()) -> Answer {
+        part1::solve1(trails).into()
+    }
+
+    fn part2(trails: &Trails, _: &()) -> Answer {
+        part2::solve2(trails).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[&str] = &[
+        "#.#####################",
+        "#.......#########...###",
+        "#######.#########.#.###",
+        "###.....#.>.>.###.#.###",
+        "###v#####.#v#.###.#.###",
+        "###.>...#.#.#.....#...#",
+        "###v###.#.#.#########.#",
+        "###...#.#.#.......#...#",
+        "#####.#.#.#######.#.###",
+        "#.....#.#.#.......#...#",
+        "#.#####.#.#.#########v#",
+        "#.#...#...#...###...>.#",
+        "#.#.#v#######v###.###v#",
+        "#...#.>.#...>.>.#.###.#",
+        "#####v#.#.###v#.#.###.#",
+        "#.....#...#...#.#.#...#",
+        "#.#########.###.#.#.###",
+        "#...###...#...#...#.###",
+        "###.###.#.###v#####v###",
+        "#...#...#.#.>.>.#.>.###",
+        "#.###.###.#.###.#.#v###",
+        "#.....###...###...#...#",
+        "#####################.#",
+    ];
+
+    #[test]
+    fn parse_errors() {
+        let trails = parse(INPUT).unwrap();
+        assert_eq!(
+            (trails.start, trails.end),
+            (Point { x: 1, y: 0 }, Point { x: 21, y: 22 })
+        );
+        let err = parse(&["#.#", "#x#", "#.#"]).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+        let err = parse(&["#.#", "#.", "#.#"]).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        let err = parse(&["#.#", "#.>", "#.#"]).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, ">"));
+        let err = parse(&["#.#", "###"]).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn test1() {
+        assert_eq!(part1::solve1(&parse(INPUT).unwrap()), 94)
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(part2::solve2(&parse(INPUT).unwrap()), 154)
+    }
+}
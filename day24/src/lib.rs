@@ -0,0 +1,230 @@
+use std::{fmt::Display, ops::RangeInclusive};
+
+use utils::{
+    parse::{self, Line, ParseError},
+    Answer, IPoint3, Solution, Vec3,
+};
+
+use z3::{
+    ast::{Ast, Bool, Int, Real},
+    Config, Context, Solver,
+};
+
+// direction from `from` to `to` in the xy plane
+fn xy_angle(from: IPoint3, to: IPoint3) -> f64 {
+    let x = (to.x - from.x) as f64;
+    let y = (to.y - from.y) as f64;
+
+    y.atan2(x).to_degrees()
+}
+
+#[derive(Debug, Clone, Copy)]
+struct XYLine {
+    p1: IPoint3,
+    p2: IPoint3,
+    m: f64,
+    b: f64,
+}
+
+impl XYLine {
+    fn new(p1: IPoint3, p2: IPoint3) -> Self {
+        let x1 = p1.x as f64;
+        let y1 = p1.y as f64;
+        let x2 = p2.x as f64;
+        let y2 = p2.y as f64;
+
+        let m = (y2 - y1) / (x2 - x1);
+        let b = y1 - m * x1;
+
+        Self { p1, p2, m, b }
+    }
+    fn intersection(&self, other: &Self) -> Option<IPoint3> {
+        if self.m == other.m {
+            // Parallel or coincident
+            return None;
+        }
+
+        let x: f64;
+        let y: f64;
+
+        if self.m.is_infinite() {
+            x = self.p1.x as f64;
+            y = other.m * x + other.b;
+        } else if other.m.is_infinite() {
+            x = other.p1.x as f64;
+            y = self.m * x + self.b;
+        } else {
+            x = (other.b - self.b) / (self.m - other.m);
+            y = self.m * x + self.b;
+        }
+
+        Some(IPoint3::new(x.round() as i64, y.round() as i64, 0))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Hailstone {
+    position: IPoint3,
+    velocity: Vec3,
+}
+
+impl Display for Hailstone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}, {}, {} @ {}, {}, {}",
+            self.position.x,
+            self.position.y,
+            self.position.z,
+            self.velocity.x,
+            self.velocity.y,
+            self.velocity.z
+        )
+    }
+}
+
+fn parse_triple(line: Line, s: &str, expected: &str) -> Result<IPoint3, ParseError> {
+    let (x, rest) = line.split_once(s, ",")?;
+    let (y, z) = line.split_once(rest, ",")?;
+
+    Ok(IPoint3::new(
+        line.parse(x.trim(), expected)?,
+        line.parse(y.trim(), expected)?,
+        line.parse(z.trim(), expected)?,
+    ))
+}
+
+pub fn parse(input: &[&str]) -> Result<Vec<Hailstone>, ParseError> {
+    parse::lines(input)
+        .map(|line| {
+            let (pos_line, vel_line) = line.split_once(line.text, " @ ")?;
+            Ok(Hailstone {
+                position: parse_triple(line, pos_line, "a position")?,
+                velocity: parse_triple(line, vel_line, "a velocity")?,
+            })
+        })
+        .collect()
+}
+
+pub fn solve1(hailstones: &[Hailstone], range_min: i64, range_max: i64) -> i64 {
+    let hailstone_paths: Vec<(Hailstone, XYLine)> = hailstones
+        .iter()
+        .map(|h| {
+            let line = XYLine::new(h.position, h.position + h.velocity);
+            (*h, line)
+        })
+        .collect();
+
+    let mut future_intersections = 0;
+    for (i, (hstone1, hpath1)) in hailstone_paths.iter().enumerate() {
+        for (hstone2, hpath2) in hailstone_paths.iter().skip(i + 1) {
+            if hstone1 == hstone2 {
+                continue;
+            }
+            if let Some(intersect) = hpath1.intersection(hpath2) {
+                if intersect.x >= range_min
+                    && intersect.x <= range_max
+                    && intersect.y >= range_min
+                    && intersect.y <= range_max
+                    && (xy_angle(hpath1.p1, hpath1.p2) - xy_angle(hpath1.p1, intersect)).abs()
+                        < 10.0
+                    && (xy_angle(hpath2.p1, hpath2.p2) - xy_angle(hpath2.p1, intersect)).abs()
+                        < 10.0
+                {
+                    future_intersections += 1;
+                }
+            }
+        }
+    }
+
+    future_intersections
+}
+
+pub fn solve2(hailstones: &[Hailstone]) -> i64 {
+    let mut config = Config::new();
+    config.set_model_generation(true);
+    let context = Context::new(&config);
+    let solver = Solver::new(&context);
+
+    let rock_x = Real::new_const(&context, "rock_x");
+    let rock_y = Real::new_const(&context, "rock_y");
+    let rock_z = Real::new_const(&context, "rock_z");
+    let rock_dx = Real::new_const(&context, "rock_dx");
+    let rock_dy = Real::new_const(&context, "rock_dy");
+    let rock_dz = Real::new_const(&context, "rock_dz");
+
+    let constraints = hailstones
+        .iter()
+        .enumerate()
+        .map(|(t, h)| {
+            let time = Real::new_const(&context, format!("t{t}"));
+            (rock_x.clone() + rock_dx.clone() * time.clone())._eq(
+                &(Int::from_i64(&context, h.position.x).to_real()
+                    + Int::from_i64(&context, h.velocity.x).to_real() * time.clone()),
+            ) & (rock_y.clone() + rock_dy.clone() * time.clone())._eq(
+                &(&Int::from_i64(&context, h.position.y).to_real()
+                    + Int::from_i64(&context, h.velocity.y).to_real() * time.clone()),
+            ) & (rock_z.clone() + rock_dz.clone() * time.clone())._eq(
+                &(Int::from_i64(&context, h.position.z).to_real()
+                    + Int::from_i64(&context, h.velocity.z).to_real() * time.clone()),
+            )
+        })
+        .fold(Bool::from_bool(&context, true), |a, o| a & o);
+
+    solver.assert(&constraints);
+    match solver.check() {
+        z3::SatResult::Sat => {
+            let model = solver.get_model().unwrap();
+            let x = model.eval(&rock_x, true).unwrap().as_real().unwrap().0;
+            let y = model.eval(&rock_y, true).unwrap().as_real().unwrap().0;
+            let z = model.eval(&rock_z, true).unwrap().as_real().unwrap().0;
+            x + y + z
+        }
+        _ => panic!("failed to sat"),
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u32 = 24;
+    type Parsed<'a> = Vec<Hailstone>;
+    // test area for part 1
+    type Params = RangeInclusive<i64>;
+    const PARAMS: RangeInclusive<i64> = 200000000000000..=400000000000000;
+
+    fn parse(input: &str) -> Result<Vec<Hailstone>, ParseError> {
+        parse(&input.lines().collect::<Vec<_>>())
+    }
+
+    fn part1(hailstones: &Vec<Hailstone>, test_area: &RangeInclusive<i64>) -> Answer {
+        solve1(hailstones, *test_area.start(), *test_area.end()).into()
+    }
+
+    fn part2(hailstones: &Vec<Hailstone>, _: &RangeInclusive<i64>) -> Answer {
+        solve2(hailstones).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[&str] = &[
+        "19, 13, 30 @ -2,  1, -2",
+        "18, 19, 22 @ -1, -1, -2",
+        "20, 25, 34 @ -2, -2, -4",
+        "12, 31, 28 @ -1, -2, -1",
+        "20, 19, 15 @  1, -5, -3",
+    ];
+
+    #[test]
+    fn test1() {
+        assert_eq!(solve1(&parse(INPUT).unwrap(), 7, 27), 2)
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(solve2(&parse(INPUT).unwrap()), 47)
+    }
+}
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+use utils::{
+    parse::{self, ParseError},
+    Answer,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+// accepted answers, one "DAY PART ANSWER" per line, # starts a comment
+#[derive(Debug, Default)]
+pub struct Answers(HashMap<(u32, u32), String>);
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let lines = text.lines().collect::<Vec<_>>();
+        let mut answers = HashMap::new();
+        for line in parse::lines(&lines) {
+            let content = line.text.trim();
+            if content.is_empty() || content.starts_with('#') {
+                continue;
+            }
+
+            let (day, rest) = line.split_once(content, " ")?;
+            let day = match line.parse(day, "a day") {
+                Ok(day @ 1..=25) => day,
+                _ => return Err(line.error(day, "a day from 1 to 25")),
+            };
+            let rest = rest.trim_start();
+            let (part, answer) = rest.split_once(' ').unwrap_or((rest, &rest[rest.len()..]));
+            let part = match part {
+                "1" => 1,
+                "2" => 2,
+                _ => return Err(line.error(part, "part 1 or 2")),
+            };
+            // an empty answer would only ever show up as a confusing mismatch
+            let answer = answer.trim();
+            if answer.is_empty() {
+                return Err(line.error(answer, "an answer"));
+            }
+            if answers.insert((day, part), answer.to_owned()).is_some() {
+                return Err(line.error(content, "a day and part not listed before"));
+            }
+        }
+
+        Ok(Self(answers))
+    }
+
+    // how many parts of `day` have an answer
+    pub fn stored(&self, day: u32) -> usize {
+        self.0.keys().filter(|(d, _)| *d == day).count()
+    }
+
+    pub fn check(&self, day: u32, part: u32, answer: &Answer) -> Verdict {
+        match self.0.get(&(day, part)) {
+            None => Verdict::Missing,
+            Some(expected) if *expected == answer.to_string() => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.clone(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_check() {
+        let answers = Answers::parse("# day part answer\n1 1 142\n\n1 2 281\n7 1 abc\n").unwrap();
+        assert_eq!(answers.check(1, 1, &Answer::from(142_u32)), Verdict::Pass);
+        assert_eq!(
+            answers.check(1, 2, &Answer::from(280_i64)),
+            Verdict::Fail {
+                expected: "281".to_owned()
+            }
+        );
+        assert_eq!(answers.check(7, 1, &Answer::from("abc")), Verdict::Pass);
+        assert_eq!(answers.check(2, 1, &Answer::from(1_u8)), Verdict::Missing);
+        assert_eq!(
+            (answers.stored(1), answers.stored(7), answers.stored(2)),
+            (2, 1, 0)
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Answers::parse("1 1 142\n26 1 5").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "26"));
+
+        let err = Answers::parse("1 3 142").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 3, "3"));
+
+        let err = Answers::parse("1 1 142\n1 1 143").unwrap_err();
+        assert_eq!(err.line, 2);
+
+        for line in ["1 1", "1 1 ", "1 1  ", "1 1 \t"] {
+            let err = Answers::parse(line).unwrap_err();
+            assert_eq!(
+                (err.column, err.expected.as_str()),
+                (4, "an answer"),
+                "{line:?}"
+            );
+        }
+    }
+}
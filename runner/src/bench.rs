@@ -0,0 +1,297 @@
+use std::{
+    fmt::Write,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::format_duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    // untimed runs before sampling starts
+    pub warmup: u32,
+    pub samples: u32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            warmup: 1,
+            samples: 10,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "need at least one sample");
+        samples.sort_unstable();
+        let len = samples.len();
+        let median = if len % 2 == 1 {
+            samples[len / 2]
+        } else {
+            (samples[len / 2 - 1] + samples[len / 2]) / 2
+        };
+
+        Self {
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / len as u32,
+            max: samples[len - 1],
+        }
+    }
+
+    fn json(&self) -> String {
+        format!(
+            "{{\"min_ns\": {}, \"median_ns\": {}, \"mean_ns\": {}, \"max_ns\": {}}}",
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.mean.as_nanos(),
+            self.max.as_nanos()
+        )
+    }
+}
+
+// stats over the samples, along with the value of the last call
+pub fn measure<T>(config: &Config, mut f: impl FnMut() -> T) -> (Stats, T) {
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+
+    let mut samples = Vec::new();
+    loop {
+        let start = Instant::now();
+        let value = black_box(f());
+        samples.push(start.elapsed());
+        if samples.len() >= config.samples as usize {
+            return (Stats::from_samples(samples), value);
+        }
+    }
+}
+
+// a part that answered with an error fails instead of being timed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartBench {
+    Timed(Stats),
+    Failed(String),
+}
+
+impl PartBench {
+    fn stats(&self) -> Option<Stats> {
+        match self {
+            PartBench::Timed(stats) => Some(*stats),
+            PartBench::Failed(_) => None,
+        }
+    }
+
+    fn json(&self) -> String {
+        match self {
+            PartBench::Timed(stats) => stats.json(),
+            PartBench::Failed(error) => format!("{{\"error\": {}}}", json_string(error)),
+        }
+    }
+}
+
+fn json_string(text: &str) -> String {
+    let mut out = "\"".to_owned();
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+
+    out
+}
+
+pub struct DayBench {
+    pub day: u32,
+    pub parse: Stats,
+    pub part1: Option<PartBench>,
+    pub part2: Option<PartBench>,
+}
+
+impl DayBench {
+    // the parts that failed, with their errors
+    pub fn failures(&self) -> impl Iterator<Item = (u32, &str)> {
+        [(1, &self.part1), (2, &self.part2)]
+            .into_iter()
+            .filter_map(|(part, bench)| match bench {
+                Some(PartBench::Failed(error)) => Some((part, error.as_str())),
+                _ => None,
+            })
+    }
+}
+
+// medians, one row per day
+pub fn table(results: &[DayBench]) -> String {
+    let cell = |bench: Option<&PartBench>| match bench {
+        Some(PartBench::Timed(stats)) => format_duration(stats.median),
+        Some(PartBench::Failed(_)) => "failed".to_owned(),
+        None => "-".to_owned(),
+    };
+
+    let mut out = format!(
+        "{:<5}{:>12}{:>12}{:>12}{:>12}\n",
+        "day", "parse", "part 1", "part 2", "total"
+    );
+    let mut total = Duration::ZERO;
+    for result in results {
+        let parts = [&result.part1, &result.part2];
+        let day_total = parts
+            .iter()
+            .filter_map(|part| part.as_ref().and_then(PartBench::stats))
+            .chain([result.parse])
+            .map(|s| s.median)
+            .sum::<Duration>();
+        total += day_total;
+        writeln!(
+            out,
+            "{:<5}{:>12}{:>12}{:>12}{:>12}",
+            format!("{:02}", result.day),
+            format_duration(result.parse.median),
+            cell(result.part1.as_ref()),
+            cell(result.part2.as_ref()),
+            format_duration(day_total)
+        )
+        .unwrap();
+    }
+    write!(out, "{:<5}{:>48}", "all", format_duration(total)).unwrap();
+
+    out
+}
+
+pub fn json(config: &Config, results: &[DayBench]) -> String {
+    let part =
+        |bench: &Option<PartBench>| bench.as_ref().map_or("null".to_owned(), PartBench::json);
+
+    let days = results
+        .iter()
+        .map(|r| {
+            format!(
+                "    {{\"day\": {}, \"parse\": {}, \"part1\": {}, \"part2\": {}}}",
+                r.day,
+                r.parse.json(),
+                part(&r.part1),
+                part(&r.part2)
+            )
+        })
+        .collect::<Vec<_>>();
+
+    format!(
+        "{{\n  \"warmup\": {},\n  \"samples\": {},\n  \"days\": [\n{}\n  ]\n}}\n",
+        config.warmup,
+        config.samples,
+        days.join(",\n")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn stats() {
+        let stats = Stats::from_samples(vec![ms(4), ms(1), ms(3)]);
+        assert_eq!(
+            stats,
+            Stats {
+                min: ms(1),
+                median: ms(3),
+                mean: Duration::from_nanos(2_666_666),
+                max: ms(4),
+            }
+        );
+        assert_eq!(
+            Stats::from_samples(vec![ms(4), ms(1), ms(3), ms(2)]).median,
+            Duration::from_micros(2500)
+        );
+    }
+
+    #[test]
+    fn measure_counts() {
+        let mut calls = 0;
+        measure(
+            &Config {
+                warmup: 2,
+                samples: 3,
+            },
+            || calls += 1,
+        );
+        assert_eq!(calls, 5);
+
+        let (_, value) = measure(
+            &Config {
+                warmup: 0,
+                samples: 0,
+            },
+            || 42,
+        );
+        assert_eq!(value, 42);
+    }
+
+    #[test]
+    fn output() {
+        let stats = Stats::from_samples(vec![ms(2)]);
+        let results = [
+            DayBench {
+                day: 7,
+                parse: stats,
+                part1: Some(PartBench::Timed(stats)),
+                part2: None,
+            },
+            DayBench {
+                day: 8,
+                parse: stats,
+                part1: Some(PartBench::Failed("no \"Z\"\n".to_owned())),
+                part2: Some(PartBench::Timed(stats)),
+            },
+        ];
+
+        let table = table(&results);
+        assert_eq!(
+            table.lines().nth(1),
+            Some("07         2.00ms      2.00ms           -      4.00ms")
+        );
+        // a failed part has no timing and adds nothing to the totals
+        assert_eq!(
+            table.lines().nth(2),
+            Some("08         2.00ms      failed      2.00ms      4.00ms")
+        );
+        assert_eq!(
+            table.lines().nth(3).map(|l| l.trim_end()),
+            Some("all                                            8.00ms")
+        );
+        assert_eq!(
+            results
+                .iter()
+                .flat_map(DayBench::failures)
+                .collect::<Vec<_>>(),
+            [(1, "no \"Z\"\n")]
+        );
+
+        let json = json(&Config::default(), &results);
+        assert!(json.contains(
+            "{\"day\": 7, \"parse\": {\"min_ns\": 2000000, \"median_ns\": 2000000, \
+             \"mean_ns\": 2000000, \"max_ns\": 2000000}, \"part1\": {\"min_ns\": 2000000, \
+             \"median_ns\": 2000000, \"mean_ns\": 2000000, \"max_ns\": 2000000}, \"part2\": null}"
+        ));
+        assert!(json.contains("\"part1\": {\"error\": \"no \\\"Z\\\"\\u000a\"}, \"part2\": {"));
+        assert!(json.starts_with("{\n  \"warmup\": 1,\n  \"samples\": 10,\n"));
+    }
+}
@@ -0,0 +1,126 @@
+use std::time::{Duration, Instant};
+
+use utils::{parse::ParseError, Answer, Solution};
+
+use crate::bench::{self, DayBench, PartBench};
+
+pub struct Timed<T> {
+    pub value: T,
+    pub elapsed: Duration,
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> Timed<T> {
+    let start = Instant::now();
+    let value = f();
+    Timed {
+        value,
+        elapsed: start.elapsed(),
+    }
+}
+
+pub struct Run {
+    pub parse: Duration,
+    pub part1: Option<Timed<Answer>>,
+    pub part2: Option<Timed<Answer>>,
+}
+
+fn run<S: Solution>(input: &str, part: Option<u32>) -> Result<Run, ParseError> {
+    let parsed = timed(|| S::parse(input));
+    let parsed = Timed {
+        value: parsed.value?,
+        elapsed: parsed.elapsed,
+    };
+    let part1 = (part != Some(2)).then(|| timed(|| S::part1(&parsed.value, &S::PARAMS)));
+    // an unsolved part 2 is only left out when it wasn't asked for
+    let part2 = (part != Some(1))
+        .then(|| timed(|| S::part2(&parsed.value, &S::PARAMS)))
+        .filter(|p| part == Some(2) || p.value != Answer::Unsolved);
+
+    Ok(Run {
+        parse: parsed.elapsed,
+        part1,
+        part2,
+    })
+}
+
+// an untimed run first, a part that fails or isn't solved doesn't get timed
+fn bench_part(config: &bench::Config, mut part: impl FnMut() -> Answer) -> Option<PartBench> {
+    match part() {
+        Answer::Unsolved => None,
+        Answer::Error(error) => Some(PartBench::Failed(error)),
+        _ => Some(PartBench::Timed(bench::measure(config, part).0)),
+    }
+}
+
+fn bench<S: Solution>(
+    input: &str,
+    part: Option<u32>,
+    config: &bench::Config,
+) -> Result<DayBench, ParseError> {
+    let parsed = S::parse(input)?;
+    let (parse, _) = bench::measure(config, || S::parse(input));
+    let part1 = (part != Some(2))
+        .then(|| bench_part(config, || S::part1(&parsed, &S::PARAMS)))
+        .flatten();
+    let part2 = (part != Some(1))
+        .then(|| bench_part(config, || S::part2(&parsed, &S::PARAMS)))
+        .flatten();
+
+    Ok(DayBench {
+        day: S::DAY,
+        parse,
+        part1,
+        part2,
+    })
+}
+
+pub struct Day {
+    pub day: u32,
+    pub run: fn(&str, Option<u32>) -> Result<Run, ParseError>,
+    pub bench: fn(&str, Option<u32>, &bench::Config) -> Result<DayBench, ParseError>,
+}
+
+impl Day {
+    fn new<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            run: run::<S>,
+            bench: bench::<S>,
+        }
+    }
+}
+
+pub fn all() -> Vec<Day> {
+    let mut days = vec![
+        Day::new::<day01::Day01>(),
+        Day::new::<day02::Day02>(),
+        Day::new::<day03::Day03>(),
+        Day::new::<day04::Day04>(),
+        Day::new::<day05::Day05>(),
+        Day::new::<day06::Day06>(),
+        Day::new::<day07::Day07>(),
+        Day::new::<day08::Day08>(),
+        Day::new::<day09::Day09>(),
+        Day::new::<day10::Day10>(),
+        Day::new::<day11::Day11>(),
+        Day::new::<day12::Day12>(),
+        Day::new::<day13::Day13>(),
+        Day::new::<day14::Day14>(),
+        Day::new::<day15::Day15>(),
+        Day::new::<day16::Day16>(),
+        Day::new::<day17::Day17>(),
+        Day::new::<day18::Day18>(),
+        Day::new::<day19::Day19>(),
+        Day::new::<day20::Day20>(),
+        Day::new::<day21::Day21>(),
+        Day::new::<day22::Day22>(),
+        Day::new::<day23::Day23>(),
+    ];
+
+    #[cfg(feature = "day24")]
+    days.push(Day::new::<day24::Day24>());
+
+    days.push(Day::new::<day25::Day25>());
+
+    days
+}
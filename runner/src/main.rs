@@ -0,0 +1,372 @@
+use std::{fs, ops::RangeInclusive, path::PathBuf, process, time::Duration};
+
+use answers::{Answers, Verdict};
+use utils::{
+    input::{self, Source},
+    Answer,
+};
+
+mod answers;
+mod bench;
+mod days;
+
+const USAGE: &str = "usage: runner [all | DAY | FIRST-LAST] [--part 1|2] \
+                     [--input PATH | --input-dir DIR] [--verify ANSWERS] \
+                     [--bench [--warmup N] [--samples N] [--json PATH]]";
+
+#[derive(Debug, PartialEq, Eq)]
+struct Args {
+    days: RangeInclusive<u32>,
+    part: Option<u32>,
+    source: Source,
+    // answers file to check the results against
+    verify: Option<PathBuf>,
+    bench: Option<bench::Config>,
+    // where to write the benchmark results as json
+    json: Option<PathBuf>,
+}
+
+fn parse_day(day: &str) -> Result<u32, String> {
+    match day.parse() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("invalid day: {day}")),
+    }
+}
+
+fn parse_count(flag: &str, val: Option<String>) -> Result<u32, String> {
+    let val = val.ok_or(format!("{flag} needs a value"))?;
+    val.parse()
+        .map_err(|_| format!("invalid value for {flag}: {val}"))
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+    let mut days = 1..=25;
+    let mut part = None;
+    let mut source = Source::Conventional;
+    let mut verify = None;
+    let mut bench = None;
+    let mut json = None;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let val = args.next().ok_or("--part needs a value")?;
+                match val.as_str() {
+                    "1" => part = Some(1),
+                    "2" => part = Some(2),
+                    _ => return Err(format!("invalid part: {val}")),
+                }
+            }
+            "--input" | "-i" => {
+                source = Source::from_arg(Some(args.next().ok_or("--input needs a path")?));
+            }
+            "--input-dir" => {
+                source = Source::Dir(args.next().ok_or("--input-dir needs a path")?.into());
+            }
+            "--verify" => {
+                verify = Some(args.next().ok_or("--verify needs a path")?.into());
+            }
+            "--bench" => {
+                bench.get_or_insert_with(bench::Config::default);
+            }
+            "--warmup" => {
+                bench.get_or_insert_with(bench::Config::default).warmup =
+                    parse_count("--warmup", args.next())?;
+            }
+            "--samples" => {
+                let samples = parse_count("--samples", args.next())?;
+                if samples == 0 {
+                    return Err("--samples must be at least 1".to_owned());
+                }
+                bench.get_or_insert_with(bench::Config::default).samples = samples;
+            }
+            "--json" => {
+                bench.get_or_insert_with(bench::Config::default);
+                json = Some(args.next().ok_or("--json needs a path")?.into());
+            }
+            "all" => days = 1..=25,
+            _ => {
+                days = if let Some((first, last)) = arg.split_once('-') {
+                    parse_day(first)?..=parse_day(last)?
+                } else {
+                    let day = parse_day(&arg)?;
+                    day..=day
+                };
+                if days.is_empty() {
+                    return Err(format!("empty range of days: {arg}"));
+                }
+            }
+        }
+    }
+
+    if matches!(source, Source::File(_) | Source::Stdin) && days.start() != days.end() {
+        return Err("--input can only be used with a single day".to_owned());
+    }
+    if bench.is_some() && verify.is_some() {
+        return Err("--verify can't be combined with benchmarking".to_owned());
+    }
+
+    Ok(Args {
+        days,
+        part,
+        source,
+        verify,
+        bench,
+        json,
+    })
+}
+
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs_f64();
+    if secs >= 1.0 {
+        format!("{secs:.2}s")
+    } else if secs >= 1e-3 {
+        format!("{:.2}ms", secs * 1e3)
+    } else {
+        format!("{:.2}µs", secs * 1e6)
+    }
+}
+
+fn report_error(day: u32, error: &str) {
+    println!("day {day:02}");
+    println!("  {}", error.replace('\n', "\n  "));
+}
+
+// days in the requested range that this build has no solution for, like day 24 without
+// its feature. Asking for one of them on its own is an error, a range just skips them
+fn unbuilt_days(args: &Args) -> (Vec<u32>, bool) {
+    let built = days::all().iter().map(|d| d.day).collect::<Vec<_>>();
+    let unbuilt = args
+        .days
+        .clone()
+        .filter(|day| !built.contains(day))
+        .collect::<Vec<_>>();
+    let single = args.days.start() == args.days.end();
+    for day in &unbuilt {
+        if single {
+            report_error(
+                *day,
+                "not built into this runner, enable its feature in runner/Cargo.toml",
+            );
+        } else {
+            println!("day {day:02} skipped, not built into this runner");
+        }
+    }
+
+    let failed = single && !unbuilt.is_empty();
+    (unbuilt, failed)
+}
+
+// returns whether any day failed
+fn run_benchmarks(args: &Args, config: &bench::Config) -> bool {
+    let (_, mut failed) = unbuilt_days(args);
+    let mut results = Vec::new();
+    for day in days::all()
+        .into_iter()
+        .filter(|d| args.days.contains(&d.day))
+    {
+        let input = match input::load(day.day, &args.source) {
+            Ok(input) => input,
+            Err(e) => {
+                report_error(day.day, &e.to_string());
+                failed = true;
+                continue;
+            }
+        };
+
+        eprintln!("benchmarking day {:02}", day.day);
+        match (day.bench)(&input, args.part, config) {
+            Ok(result) => {
+                for (part, error) in result.failures() {
+                    report_error(day.day, &format!("part {part}: {error}"));
+                    failed = true;
+                }
+                results.push(result);
+            }
+            Err(e) => {
+                report_error(day.day, &e.render(&input));
+                failed = true;
+            }
+        }
+    }
+
+    println!("{}", bench::table(&results));
+    if let Some(path) = &args.json {
+        if let Err(e) = fs::write(path, bench::json(config, &results)) {
+            eprintln!("failed to write {}: {e}", path.display());
+            failed = true;
+        }
+    }
+
+    failed
+}
+
+fn main() {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}");
+            eprintln!("{USAGE}");
+            process::exit(2);
+        }
+    };
+
+    if let Some(config) = &args.bench {
+        if run_benchmarks(&args, config) {
+            process::exit(1);
+        }
+        return;
+    }
+
+    let answers = args.verify.as_ref().map(|path| {
+        let text = fs::read_to_string(path).unwrap_or_else(|e| {
+            eprintln!("failed to read {}: {e}", path.display());
+            process::exit(2);
+        });
+        Answers::parse(&text).unwrap_or_else(|e| {
+            eprintln!("{}: {}", path.display(), e.render(&text));
+            process::exit(2);
+        })
+    });
+
+    let (unbuilt, mut failed) = unbuilt_days(&args);
+    let mut total = Duration::ZERO;
+    let (mut passed, mut mismatched, mut missing) = (0, 0, 0);
+    for day in days::all()
+        .into_iter()
+        .filter(|d| args.days.contains(&d.day))
+    {
+        let input = match input::load(day.day, &args.source) {
+            Ok(input) => input,
+            Err(e) => {
+                report_error(day.day, &e.to_string());
+                failed = true;
+                continue;
+            }
+        };
+
+        let run = match (day.run)(&input, args.part) {
+            Ok(run) => run,
+            Err(e) => {
+                report_error(day.day, &e.render(&input));
+                failed = true;
+                continue;
+            }
+        };
+        println!("day {:02} (parse {})", day.day, format_duration(run.parse));
+        total += run.parse;
+        for (part, answer) in [(1, run.part1), (2, run.part2)] {
+            if let Some(answer) = answer {
+                if answer.value == Answer::Unsolved {
+                    println!("  part {part}: unsolved, day {} has no such part", day.day);
+                    continue;
+                }
+                failed |= matches!(answer.value, Answer::Error(_));
+                let verdict = match answers
+                    .as_ref()
+                    .map(|a| a.check(day.day, part, &answer.value))
+                {
+                    None => String::new(),
+                    Some(Verdict::Pass) => {
+                        passed += 1;
+                        " ok".to_owned()
+                    }
+                    Some(Verdict::Fail { expected }) => {
+                        mismatched += 1;
+                        format!(" FAIL, expected {expected}")
+                    }
+                    Some(Verdict::Missing) => {
+                        missing += 1;
+                        " no stored answer".to_owned()
+                    }
+                };
+                println!(
+                    "  part {part}: {} ({}){verdict}",
+                    answer.value,
+                    format_duration(answer.elapsed)
+                );
+                total += answer.elapsed;
+            }
+        }
+    }
+
+    println!("total: {}", format_duration(total));
+    if let Some(answers) = &answers {
+        let skipped: usize = unbuilt.iter().map(|day| answers.stored(*day)).sum();
+        println!(
+            "verified: {passed} passed, {mismatched} failed, {missing} missing, {skipped} skipped"
+        );
+        failed |= mismatched > 0;
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Result<Args, String> {
+        parse_args(args.iter().map(|a| a.to_string()))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            args(&[]),
+            Ok(Args {
+                days: 1..=25,
+                part: None,
+                source: Source::Conventional,
+                verify: None,
+                bench: None,
+                json: None,
+            })
+        );
+        assert_eq!(
+            args(&["7", "--part", "2", "--input", "-"]),
+            Ok(Args {
+                days: 7..=7,
+                part: Some(2),
+                source: Source::Stdin,
+                verify: None,
+                bench: None,
+                json: None,
+            })
+        );
+        assert_eq!(
+            args(&["3-12", "--input-dir", "inputs"]),
+            Ok(Args {
+                days: 3..=12,
+                part: None,
+                source: Source::Dir("inputs".into()),
+                verify: None,
+                bench: None,
+                json: None,
+            })
+        );
+        assert_eq!(
+            args(&["--verify", "answers.txt"]).map(|a| a.verify),
+            Ok(Some("answers.txt".into()))
+        );
+        assert!(args(&["--verify"]).is_err());
+        assert_eq!(
+            args(&["--bench", "--samples", "3", "--json", "bench.json"]).map(|a| (a.bench, a.json)),
+            Ok((
+                Some(bench::Config {
+                    warmup: 1,
+                    samples: 3
+                }),
+                Some("bench.json".into())
+            ))
+        );
+        assert!(args(&["--samples", "0"]).is_err());
+        assert!(args(&["--bench", "--verify", "answers.txt"]).is_err());
+        assert!(args(&["26"]).is_err());
+        assert!(args(&["12-3"]).is_err());
+        assert!(args(&["1", "--part", "3"]).is_err());
+        assert!(args(&["1-3", "--input", "day01.txt"]).is_err());
+    }
+}
@@ -0,0 +1,232 @@
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, Hash, RandomState},
+};
+
+// the states of x, f(x), f(f(x)), ... are all distinct for the first `prefix`
+// steps, after which they repeat every `period` steps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    // the earliest step with the same state as step `n`
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+}
+
+// Brent's algorithm, only keeps two states around at a time
+pub fn brent<T: Clone + PartialEq>(start: T, mut step: impl FnMut(&T) -> T) -> Cycle {
+    // find the period by moving the tortoise up to the hare at each power of two
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // then walk two states `period` apart until they meet at the start of the cycle
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    Cycle { prefix, period }
+}
+
+// Floyd's tortoise and hare, same result as `brent` with more calls to `step`
+pub fn floyd<T: Clone + PartialEq>(start: T, mut step: impl FnMut(&T) -> T) -> Cycle {
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let next = step(&hare);
+        hare = step(&next);
+    }
+
+    let mut prefix = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    let mut period = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        period += 1;
+    }
+
+    Cycle { prefix, period }
+}
+
+// the state at step `n`, only simulating up to the end of the first period
+pub fn nth<T: Clone + PartialEq>(start: T, mut step: impl FnMut(&T) -> T, n: usize) -> T {
+    let cycle = brent(start.clone(), &mut step);
+    (0..cycle.reduce(n)).fold(start, |state, _| step(&state))
+}
+
+// every state up to the point where they start repeating
+#[derive(Debug, Clone)]
+pub struct History<T> {
+    pub cycle: Cycle,
+    // `cycle.prefix + cycle.period` states, starting with the initial one
+    pub states: Vec<T>,
+}
+
+impl<T> History<T> {
+    pub fn state_at(&self, n: usize) -> &T {
+        &self.states[self.cycle.reduce(n)]
+    }
+}
+
+// steps until a state comes up a second time, remembering all of them on the way.
+// Each state is only stepped once, which is cheaper than `brent` when steps are slow
+pub fn find_hashed<T: Eq + Hash>(start: T, step: impl FnMut(&T) -> T) -> History<T> {
+    let Ok(history) = find_hashed_within(start, step, usize::MAX) else {
+        unreachable!("more states than fit in memory");
+    };
+    history
+}
+
+// like `find_hashed`, but gives up with the state after `steps` steps if nothing
+// repeated before it, for when the answer is needed at that step anyway
+pub fn find_hashed_within<T: Eq + Hash>(
+    start: T,
+    mut step: impl FnMut(&T) -> T,
+    steps: usize,
+) -> Result<History<T>, T> {
+    // states are only kept in `states`, this maps their hashes to indices into it
+    let hasher = RandomState::new();
+    let mut seen: HashMap<u64, Vec<usize>> = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = start;
+    loop {
+        if states.len() == steps {
+            return Err(state);
+        }
+
+        let hash = hasher.hash_one(&state);
+        let same_hash = seen.entry(hash).or_default();
+        if let Some(&prefix) = same_hash.iter().find(|&&idx| states[idx] == state) {
+            return Ok(History {
+                cycle: Cycle {
+                    prefix,
+                    period: states.len() - prefix,
+                },
+                states,
+            });
+        }
+
+        same_hash.push(states.len());
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 3 -> 10 -> 5 -> 16 -> 8 -> 4 -> 2 -> 1 -> 4 -> ...
+    fn collatz(n: &u64) -> u64 {
+        if n.is_multiple_of(2) {
+            n / 2
+        } else {
+            3 * n + 1
+        }
+    }
+
+    #[test]
+    fn finders_agree() {
+        let expected = Cycle {
+            prefix: 5,
+            period: 3,
+        };
+        assert_eq!(brent(3, collatz), expected);
+        assert_eq!(floyd(3, collatz), expected);
+        assert_eq!(find_hashed(3, collatz).cycle, expected);
+
+        // a pure cycle with no prefix
+        let rotate = |n: &u32| (n + 1) % 7;
+        for cycle in [
+            brent(0, rotate),
+            floyd(0, rotate),
+            find_hashed(0, rotate).cycle,
+        ] {
+            assert_eq!(
+                cycle,
+                Cycle {
+                    prefix: 0,
+                    period: 7
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn extrapolate() {
+        let history = find_hashed(3, collatz);
+        assert_eq!(history.states, [3, 10, 5, 16, 8, 4, 2, 1]);
+        assert_eq!(*history.state_at(4), 8);
+        assert_eq!(*history.state_at(1_000_000_000), 1);
+        assert_eq!(nth(3, collatz, 1_000_000_000), 1);
+        assert_eq!(history.cycle.reduce(11), 5);
+
+        // the loop starts at step 5 and is first seen again at step 8
+        assert_eq!(find_hashed_within(3, collatz, 0).unwrap_err(), 3);
+        assert_eq!(find_hashed_within(3, collatz, 4).unwrap_err(), 8);
+        assert_eq!(find_hashed_within(3, collatz, 7).unwrap_err(), 1);
+        assert_eq!(find_hashed_within(3, collatz, 8).unwrap_err(), 4);
+        let within = find_hashed_within(3, collatz, 9).unwrap();
+        assert_eq!(
+            (within.cycle, within.states),
+            (history.cycle, history.states)
+        );
+    }
+
+    // every state hashes the same, so only the equality check tells them apart
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Colliding(u64);
+
+    impl Hash for Colliding {
+        fn hash<H: std::hash::Hasher>(&self, _: &mut H) {}
+    }
+
+    #[test]
+    fn hash_collisions() {
+        let history = find_hashed(Colliding(3), |n| Colliding(collatz(&n.0)));
+        assert_eq!(
+            history.cycle,
+            Cycle {
+                prefix: 5,
+                period: 3
+            }
+        );
+        assert_eq!(history.states.len(), 8);
+    }
+}
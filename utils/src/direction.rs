@@ -0,0 +1,214 @@
+use std::{fmt, str::FromStr};
+
+use crate::{checked_offset, IPoint, Point};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+// the 4 directions plus the diagonals between them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidDirection(pub String);
+
+impl fmt::Display for InvalidDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected a direction (UDLR, NESW or ^>v<), found {:?}",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for InvalidDirection {}
+
+impl Direction {
+    // clockwise, starting up
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.index() + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self.index() + 2) % 4]
+    }
+
+    // one step in this direction, y grows downwards
+    pub fn delta(self) -> IPoint {
+        match self {
+            Self::Up => IPoint::UP,
+            Self::Right => IPoint::RIGHT,
+            Self::Down => IPoint::DOWN,
+            Self::Left => IPoint::LEFT,
+        }
+    }
+
+    // None when the step would go below 0, there's no upper bound
+    pub fn step(self, point: Point) -> Option<Point> {
+        let delta = self.delta();
+        Some(Point {
+            x: checked_offset(point.x, delta.x as i32)?,
+            y: checked_offset(point.y, delta.y as i32)?,
+        })
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = InvalidDirection;
+
+    fn try_from(chr: char) -> Result<Self, Self::Error> {
+        match chr {
+            'U' | 'N' | '^' => Ok(Self::Up),
+            'R' | 'E' | '>' => Ok(Self::Right),
+            'D' | 'S' | 'v' => Ok(Self::Down),
+            'L' | 'W' | '<' => Ok(Self::Left),
+            _ => Err(InvalidDirection(chr.to_string())),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = InvalidDirection;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(chr), None) => Self::try_from(chr).map_err(|_| InvalidDirection(s.to_owned())),
+            _ => Err(InvalidDirection(s.to_owned())),
+        }
+    }
+}
+
+impl Direction8 {
+    // clockwise, starting up
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    // by 45 degrees
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    pub fn delta(self) -> IPoint {
+        match self {
+            Self::Up => IPoint::UP,
+            Self::UpRight => IPoint::UP + IPoint::RIGHT,
+            Self::Right => IPoint::RIGHT,
+            Self::DownRight => IPoint::DOWN + IPoint::RIGHT,
+            Self::Down => IPoint::DOWN,
+            Self::DownLeft => IPoint::DOWN + IPoint::LEFT,
+            Self::Left => IPoint::LEFT,
+            Self::UpLeft => IPoint::UP + IPoint::LEFT,
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(dir: Direction) -> Self {
+        match dir {
+            Direction::Up => Self::Up,
+            Direction::Right => Self::Right,
+            Direction::Down => Self::Down,
+            Direction::Left => Self::Left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turning() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        assert!(Direction::iter().all(|d| d.turn_left().turn_right() == d));
+        assert!(Direction::iter().all(|d| d.delta() == -d.opposite().delta()));
+
+        assert_eq!(Direction8::UpLeft.turn_right(), Direction8::Up);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownRight.opposite(), Direction8::UpLeft);
+        assert_eq!(Direction8::iter().filter(|d| d.is_diagonal()).count(), 4);
+        assert!(Direction::iter().all(|d| Direction8::from(d).delta() == d.delta()));
+    }
+
+    #[test]
+    fn stepping() {
+        let p = Point { x: 0, y: 3 };
+        assert_eq!(Direction::Down.step(p), Some(Point { x: 0, y: 4 }));
+        assert_eq!(Direction::Left.step(p), None);
+        assert_eq!(Direction8::DownLeft.delta(), IPoint::new(-1, 1));
+    }
+
+    #[test]
+    fn parsing() {
+        for (encoding, expected) in ["URDL", "NESW", "^>v<"].iter().zip([Direction::ALL; 3]) {
+            let parsed = encoding.chars().map(|c| Direction::try_from(c).unwrap());
+            assert!(parsed.eq(expected));
+        }
+        assert_eq!("R".parse(), Ok(Direction::Right));
+        assert_eq!(
+            "RR".parse::<Direction>(),
+            Err(InvalidDirection("RR".to_owned()))
+        );
+        assert!(Direction::try_from('x').is_err());
+    }
+}
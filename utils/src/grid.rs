@@ -0,0 +1,298 @@
+use std::{
+    iter::StepBy,
+    ops::{Index, IndexMut},
+    slice,
+};
+
+use crate::{
+    checked_offset,
+    parse::{self, ParseError},
+    Offset, Point,
+};
+
+const ADJACENT_OFFSETS: [(i32, i32); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+const LIMITED_ADJACENT_OFFSETS: [(i32, i32); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+pub type Column<'a, T> = StepBy<slice::Iter<'a, T>>;
+
+// owned grid, stored row by row in a single Vec
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid2D<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid2D<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point { x, y }))
+            .map(&mut f)
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // `cells` in row-major order, its length has to be a multiple of `width`
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        assert_eq!(width * height, cells.len(), "cells don't fill the last row");
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // one cell per char, `f` returns None for chars it doesn't accept
+    pub fn parse(
+        input: &[&str],
+        expected: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut cells = Vec::new();
+        for line in parse::lines(input) {
+            let row = parse::row(line, expected, &mut f)?;
+            let width = *width.get_or_insert(row.len());
+            parse::check_width(line, row.len(), width)?;
+            cells.extend(row);
+        }
+
+        // only empty lines make an empty grid, like `from_cells` with no cells
+        let width = width.unwrap_or(0);
+        Ok(Self {
+            width,
+            height: if width == 0 { 0 } else { input.len() },
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn offset(&self, point: Point, x_offset: i32, y_offset: i32) -> Option<Point> {
+        let offset = Point {
+            x: checked_offset(point.x, x_offset)?,
+            y: checked_offset(point.y, y_offset)?,
+        };
+
+        self.contains(offset).then_some(offset)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> slice::Chunks<'_, T> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> Column<'_, T> {
+        assert!(x < self.width, "column {x} out of bounds");
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = Column<'_, T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point { x, y }))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        self.points().zip(self.cells.iter_mut())
+    }
+
+    fn around<'a>(
+        &'a self,
+        point: Point,
+        offsets: &'a [(i32, i32)],
+    ) -> impl Iterator<Item = Offset<&'a T>> {
+        offsets.iter().filter_map(move |(x_offset, y_offset)| {
+            let Point { x, y } = self.offset(point, *x_offset, *y_offset)?;
+            Some(Offset {
+                x,
+                y,
+                val: &self[Point { x, y }],
+            })
+        })
+    }
+
+    // all 8 neighbours inside the grid
+    pub fn adjacents(&self, point: Point) -> impl Iterator<Item = Offset<&T>> {
+        self.around(point, &ADJACENT_OFFSETS)
+    }
+
+    // only the 4 orthogonal neighbours inside the grid
+    pub fn limited_adjacents(&self, point: Point) -> impl Iterator<Item = Offset<&T>> {
+        self.around(point, &LIMITED_ADJACENT_OFFSETS)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid2D<U> {
+        Grid2D {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn swap(&mut self, a: Point, b: Point) {
+        assert!(self.contains(a) && self.contains(b), "swap out of bounds");
+        self.cells
+            .swap(a.y * self.width + a.x, b.y * self.width + b.x);
+    }
+}
+
+impl<T> Index<Point> for Grid2D<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} out of bounds"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid2D<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} out of bounds"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid2D<u32> {
+        Grid2D::parse(&["123", "456", "789"], "a digit", |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn access() {
+        let mut grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid[Point { x: 2, y: 1 }], 6);
+        assert_eq!(grid.get(Point { x: 3, y: 1 }), None);
+        assert_eq!(grid.row(2), &[7, 8, 9]);
+        assert!(grid.column(1).eq(&[2, 5, 8]));
+        assert_eq!(grid.columns().count(), 3);
+
+        grid[Point { x: 0, y: 0 }] = 10;
+        grid.swap(Point { x: 0, y: 0 }, Point { x: 2, y: 2 });
+        grid.row_mut(1)[1] = 0;
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            [[9, 2, 3], [4, 0, 6], [7, 8, 10]]
+        );
+        assert!(grid.map(|v| v % 2 == 0)[Point { x: 2, y: 2 }]);
+    }
+
+    #[test]
+    fn adjacents() {
+        let grid = grid();
+        let center = grid
+            .adjacents(Point { x: 1, y: 1 })
+            .map(|o| *o.val)
+            .collect::<Vec<_>>();
+        assert_eq!(center, [1, 2, 3, 4, 6, 7, 8, 9]);
+        assert_eq!(grid.adjacents(Point { x: 0, y: 0 }).count(), 3);
+
+        let corner = grid
+            .limited_adjacents(Point { x: 2, y: 2 })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            corner,
+            [
+                Offset {
+                    x: 2,
+                    y: 1,
+                    val: &6
+                },
+                Offset {
+                    x: 1,
+                    y: 2,
+                    val: &8
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn construct() {
+        let grid = Grid2D::from_fn(3, 2, |p| p.x + p.y * 3);
+        assert_eq!(grid, Grid2D::from_cells(3, vec![0, 1, 2, 3, 4, 5]));
+        assert_eq!(grid.iter().nth(4), Some((Point { x: 1, y: 1 }, &4)));
+        assert_eq!(Grid2D::new(2, 2, 'a').row(1), &['a', 'a']);
+
+        let empty = Grid2D::parse(&["", ""], "a digit", |c| c.to_digit(10)).unwrap();
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+        assert_eq!(empty, Grid2D::from_cells(0, vec![]));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Grid2D::parse(&["12", "3x"], "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(err, ParseError::new(2, 2, "x", "a digit"));
+
+        let err = Grid2D::parse(&["123", "45"], "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        let err = Grid2D::parse(&["12", "345"], "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "5"));
+    }
+}
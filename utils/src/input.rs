@@ -0,0 +1,134 @@
+use std::{
+    env, fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    // a specific file
+    File(PathBuf),
+    Stdin,
+    // a directory holding dayNN.txt files
+    Dir(PathBuf),
+    // dayNN/src/input.txt, relative to the current directory or the workspace
+    Conventional,
+}
+
+impl Source {
+    pub fn from_arg(arg: Option<String>) -> Self {
+        match arg.as_deref() {
+            None => Source::Conventional,
+            Some("-") => Source::Stdin,
+            Some(path) => Source::File(path.into()),
+        }
+    }
+
+    fn candidates(&self, day: u32) -> Vec<PathBuf> {
+        match self {
+            Source::File(path) => vec![path.clone()],
+            Source::Stdin => Vec::new(),
+            Source::Dir(dir) => vec![dir.join(format!("day{day:02}.txt"))],
+            Source::Conventional => {
+                let relative = PathBuf::from(format!("day{day:02}/src/input.txt"));
+                let workspace = Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .parent()
+                    .unwrap()
+                    .join(&relative);
+                let mut candidates = vec![relative];
+                if !candidates.contains(&workspace) {
+                    candidates.push(workspace);
+                }
+                candidates
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound { day: u32, tried: Vec<PathBuf> },
+    Read { path: PathBuf, error: io::Error },
+    Stdin(io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound { day, tried } => {
+                write!(f, "no input found for day {day}, tried:")?;
+                for path in tried {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                Ok(())
+            }
+            InputError::Read { path, error } => {
+                write!(f, "failed to read {}: {error}", path.display())
+            }
+            InputError::Stdin(error) => write!(f, "failed to read stdin: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+pub fn load(day: u32, source: &Source) -> Result<String, InputError> {
+    if let Source::Stdin = source {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(InputError::Stdin)?;
+        return Ok(input);
+    }
+
+    let tried = source.candidates(day);
+    for path in tried.iter() {
+        match fs::read_to_string(path) {
+            Ok(input) => return Ok(input),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(error) => {
+                return Err(InputError::Read {
+                    path: path.clone(),
+                    error,
+                })
+            }
+        }
+    }
+
+    Err(InputError::NotFound { day, tried })
+}
+
+// input path from the first command line argument, "-" for stdin
+pub fn from_args(day: u32) -> Result<String, InputError> {
+    load(day, &Source::from_arg(env::args().nth(1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conventional_location() {
+        let input = load(1, &Source::Conventional);
+        assert!(input.is_ok());
+    }
+
+    #[test]
+    fn missing_file() {
+        let source = Source::from_arg(Some("no/such/input.txt".to_owned()));
+        let Err(InputError::NotFound { day, tried }) = load(3, &source) else {
+            panic!("expected NotFound");
+        };
+        assert_eq!(day, 3);
+        assert_eq!(tried, vec![PathBuf::from("no/such/input.txt")]);
+    }
+
+    #[test]
+    fn dir_source() {
+        let source = Source::Dir("inputs".into());
+        assert_eq!(
+            source.candidates(7),
+            vec![PathBuf::from("inputs/day07.txt")]
+        );
+    }
+}
@@ -1,4 +1,19 @@
-// mod pathfinding;
+pub mod cycle;
+pub mod direction;
+pub mod grid;
+pub mod input;
+pub mod math;
+pub mod parse;
+pub mod pathfinding;
+pub mod point;
+#[cfg(any(test, feature = "rng"))]
+pub mod rng;
+pub mod solution;
+
+pub use direction::{Direction, Direction8};
+pub use grid::Grid2D;
+pub use point::{IPoint, IPoint3, Vec2, Vec3};
+pub use solution::{Answer, Solution};
 
 pub trait Grid: Sized {
     type Item;
@@ -21,7 +36,7 @@ pub trait Grid: Sized {
         }
     }
 
-    fn adjacents(&self, x: usize, y: usize) -> Adjacents<Self> {
+    fn adjacents(&self, x: usize, y: usize) -> Adjacents<'_, Self> {
         Adjacents {
             grid: self,
             center_loc: (x, y),
@@ -29,7 +44,7 @@ pub trait Grid: Sized {
         }
     }
 
-    fn limited_adjacents(&self, x: usize, y: usize) -> LimitedAdjacents<Self> {
+    fn limited_adjacents(&self, x: usize, y: usize) -> LimitedAdjacents<'_, Self> {
         LimitedAdjacents {
             grid: self,
             center_loc: (x, y),
@@ -37,7 +52,7 @@ pub trait Grid: Sized {
         }
     }
 
-    fn iter_by_column(&self) -> Columns<Self> {
+    fn iter_by_column(&self) -> Columns<'_, Self> {
         Columns {
             grid: self,
             cur_col: 0,
@@ -218,27 +233,6 @@ pub struct Point {
     pub y: usize,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
-
-pub fn gcd(mut a: u64, mut b: u64) -> u64 {
-    while a > 0 && b > 0 {
-        (a, b) = (a.max(b), a.min(b));
-        (a, b) = (b, a % b);
-    }
-
-    a
-}
-
-pub fn lcm(a: u64, b: u64) -> u64 {
-    (a * b) / gcd(a, b)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
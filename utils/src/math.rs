@@ -0,0 +1,139 @@
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b > 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+// None if the result doesn't fit in a u64
+pub fn checked_lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+pub fn lcm(a: u64, b: u64) -> u64 {
+    checked_lcm(a, b).unwrap_or_else(|| panic!("lcm of {a} and {b} overflows a u64"))
+}
+
+// lcm of every value, 1 for none at all
+pub fn lcm_all(values: impl IntoIterator<Item = u64>) -> Option<u64> {
+    values.into_iter().try_fold(1, checked_lcm)
+}
+
+// (g, x, y) with a * x + b * y == g == gcd(a, b), g is never negative
+pub fn ext_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+// x in 0..modulus with a * x ≡ 1, None unless a and modulus are coprime
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    let (g, x, _) = ext_gcd(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrtError {
+    // no x satisfies both congruences
+    Contradiction,
+    // the lcm of the moduli doesn't fit in an i64
+    Overflow,
+}
+
+// the (x, lcm) solving both (x, m) and (residue, modulus) as in `crt`, for callers
+// that need to tell a contradiction from an overflow
+pub fn crt_combine(
+    (x, m): (i64, i64),
+    (residue, modulus): (i64, i64),
+) -> Result<(i64, i64), CrtError> {
+    assert!(modulus > 0, "crt moduli have to be positive");
+    let (g, p, _) = ext_gcd(m, modulus);
+    let diff = residue.rem_euclid(modulus) - x;
+    if diff % g != 0 {
+        return Err(CrtError::Contradiction);
+    }
+
+    // x + m * k satisfies both when k ≡ diff / g * p (mod modulus / g)
+    let step = (modulus / g) as i128;
+    let k = ((diff / g) as i128 * p as i128).rem_euclid(step);
+    let lcm = i64::try_from(m as i128 * step).map_err(|_| CrtError::Overflow)?;
+    let x = (x as i128 + m as i128 * k).rem_euclid(lcm as i128);
+
+    Ok((x as i64, lcm))
+}
+
+// smallest non-negative x with x ≡ residue (mod modulus) for every pair, along with
+// the lcm of the moduli. The moduli don't have to be coprime; None when the
+// congruences contradict each other or the lcm doesn't fit in an i64
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |acc, congruence| crt_combine(acc, congruence).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_lcm() {
+        assert_eq!(gcd(48, 18), 6);
+        assert_eq!(gcd(18, 27), 9);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(lcm(21, 6), 42);
+        assert_eq!(lcm_all([2, 3, 4]), Some(12));
+        assert_eq!(lcm_all([]), Some(1));
+
+        // a * b overflows but the lcm itself doesn't
+        let big = 1 << 40;
+        assert_eq!(checked_lcm(big, big * 2), Some(big * 2));
+        assert_eq!(lcm_all([u64::MAX, u64::MAX - 1]), None);
+    }
+
+    #[test]
+    fn euclid() {
+        let (g, x, y) = ext_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+        assert_eq!(ext_gcd(-4, 6).0, 2);
+
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(4, 8), None);
+    }
+
+    #[test]
+    fn chinese_remainder() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // non-coprime moduli
+        assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt([]), Some((0, 1)));
+        assert_eq!(crt([(0, i64::MAX), (1, i64::MAX - 1)]), None);
+
+        assert_eq!(crt_combine((2, 4), (4, 6)), Ok((10, 12)));
+        assert_eq!(crt_combine((1, 4), (2, 6)), Err(CrtError::Contradiction));
+        assert_eq!(
+            crt_combine((0, i64::MAX), (1, i64::MAX - 1)),
+            Err(CrtError::Overflow)
+        );
+    }
+}
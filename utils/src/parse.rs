@@ -0,0 +1,221 @@
+use std::{fmt, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    // both 1-based
+    pub line: usize,
+    pub column: usize,
+    // the offending text, empty when something is missing
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        expected: impl Into<String>,
+    ) -> Self {
+        Self {
+            line,
+            column,
+            text: text.into(),
+            expected: expected.into(),
+        }
+    }
+
+    // for things missing from the input as a whole, points just past the last line
+    pub fn end_of_input(num_lines: usize, expected: impl Into<String>) -> Self {
+        Self::new(num_lines + 1, 1, "", expected)
+    }
+
+    fn found(&self) -> String {
+        if self.text.is_empty() {
+            "nothing".to_owned()
+        } else {
+            format!("{:?}", self.text)
+        }
+    }
+
+    // error message followed by the offending line with the text underlined
+    pub fn render(&self, input: &str) -> String {
+        let mut out = format!(
+            "error: expected {}, found {}\n",
+            self.expected,
+            self.found()
+        );
+        let Some(source_line) = input.lines().nth(self.line - 1) else {
+            out.push_str(&format!(" --> line {}: end of input", self.line));
+            return out;
+        };
+
+        let line_num = self.line.to_string();
+        let gutter = " ".repeat(line_num.len());
+        let text_len = self.text.chars().count().max(1);
+        let underline_len = text_len.min(
+            (source_line.chars().count() + 1)
+                .saturating_sub(self.column)
+                .max(1),
+        );
+        out.push_str(&format!(
+            "{gutter}--> line {}, column {}\n",
+            self.line, self.column
+        ));
+        out.push_str(&format!("{gutter} |\n"));
+        out.push_str(&format!("{line_num} | {source_line}\n"));
+        out.push_str(&format!(
+            "{gutter} | {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(underline_len)
+        ));
+
+        out
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line,
+            self.column,
+            self.expected,
+            self.found()
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    // 0-based, like the index into the input slice
+    pub index: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(index: usize, text: &'a str) -> Self {
+        Self { index, text }
+    }
+
+    // `at` should be a slice of this line, its position gives the column
+    pub fn error(&self, at: &str, expected: impl Into<String>) -> ParseError {
+        let line_start = self.text.as_ptr() as usize;
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(line_start)
+            .filter(|o| *o <= self.text.len() && self.text.is_char_boundary(*o))
+            .unwrap_or(0);
+
+        ParseError::new(
+            self.index + 1,
+            self.text[..offset].chars().count() + 1,
+            at,
+            expected,
+        )
+    }
+
+    // error for the char starting at byte offset `idx`, the end of the line if past it
+    pub fn char_error(&self, idx: usize, expected: impl Into<String>) -> ParseError {
+        let at = self
+            .text
+            .get(idx..)
+            .and_then(|rest| rest.chars().next().map(|c| &rest[..c.len_utf8()]))
+            .unwrap_or(&self.text[self.text.len()..]);
+        self.error(at, expected)
+    }
+
+    pub fn split_once(
+        &self,
+        s: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(delimiter)
+            .ok_or_else(|| self.error(s, format!("{delimiter:?}")))
+    }
+
+    pub fn parse<T: FromStr>(&self, s: &'a str, expected: &str) -> Result<T, ParseError> {
+        s.parse().map_err(|_| self.error(s, expected))
+    }
+}
+
+pub fn lines<'a, 'b>(input: &'b [&'a str]) -> impl Iterator<Item = Line<'a>> + 'b {
+    input
+        .iter()
+        .enumerate()
+        .map(|(index, text)| Line::new(index, text))
+}
+
+// one value per char, `f` returns None for chars it doesn't accept
+pub fn row<T>(
+    line: Line,
+    expected: &str,
+    mut f: impl FnMut(char) -> Option<T>,
+) -> Result<Vec<T>, ParseError> {
+    line.text
+        .char_indices()
+        .map(|(idx, c)| f(c).ok_or_else(|| line.char_error(idx, expected)))
+        .collect()
+}
+
+// error at the char past `width` in a row that isn't `width` chars long
+pub(crate) fn check_width(line: Line, row_len: usize, width: usize) -> Result<(), ParseError> {
+    if row_len == width {
+        return Ok(());
+    }
+    let idx = line
+        .text
+        .char_indices()
+        .nth(width)
+        .map_or(line.text.len(), |c| c.0);
+    Err(line.char_error(idx, "a row as wide as the first"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_errors() {
+        let line = Line::new(2, "Game 12: 3 blue");
+        let (_, rest) = line.split_once(line.text, ": ").unwrap();
+        let (count, color) = line.split_once(rest, " ").unwrap();
+        assert_eq!(line.parse::<u32>(count, "a number"), Ok(3));
+
+        let err = line.parse::<u32>(color, "a number").unwrap_err();
+        assert_eq!(err, ParseError::new(3, 12, "blue", "a number"));
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 12: expected a number, found \"blue\""
+        );
+
+        let err = line.split_once(color, ",").unwrap_err();
+        assert_eq!(err.column, 12);
+        assert_eq!(err.expected, "\",\"");
+
+        assert_eq!(line.char_error(0, "a digit").text, "G");
+        assert_eq!(line.char_error(100, "a digit").column, 16);
+    }
+
+    #[test]
+    fn render() {
+        let input = "1,2,3\n4,x,6\n";
+        let err = ParseError::new(2, 3, "x", "a number");
+        assert_eq!(
+            err.render(input),
+            "error: expected a number, found \"x\"\n \
+             --> line 2, column 3\n  \
+             |\n\
+             2 | 4,x,6\n  \
+             |   ^"
+        );
+
+        let err = ParseError::end_of_input(2, "a blank line");
+        assert_eq!(
+            err.render(input),
+            "error: expected a blank line, found nothing\n --> line 3: end of input"
+        );
+    }
+}
@@ -0,0 +1,241 @@
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+// result of a search, covers every node reached before it stopped
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    pub distances: HashMap<N, C>,
+    // the node each reached node was first (or most cheaply) reached from
+    pub predecessors: HashMap<N, N>,
+    // the first node popped that matched the goal predicate
+    pub goal: Option<N>,
+}
+
+impl<N, C> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy,
+{
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    // start to `node`, both included
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+        while let Some(prev) = self.predecessors.get(path.last().unwrap()) {
+            path.push(prev.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    pub fn goal_distance(&self) -> Option<C> {
+        self.goal.as_ref().and_then(|goal| self.distance(goal))
+    }
+
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.goal.as_ref().and_then(|goal| self.path_to(goal))
+    }
+}
+
+// every step costs 1, pass `|_| false` as goal to explore everything reachable
+pub fn bfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search {
+        distances: HashMap::from([(start.clone(), 0)]),
+        predecessors: HashMap::new(),
+        goal: None,
+    };
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((node, dist)) = queue.pop_front() {
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        for next in successors(&node) {
+            if let Entry::Vacant(e) = search.distances.entry(next.clone()) {
+                e.insert(dist + 1);
+                search.predecessors.insert(next.clone(), node.clone());
+                queue.push_back((next, dist + 1));
+            }
+        }
+    }
+
+    search
+}
+
+// successors yield (node, cost of the step there), costs must not be negative
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+// like dijkstra, the heuristic must never overestimate the remaining cost
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    // ordered by estimate only, reversed so the heap pops the cheapest first
+    struct State<N, C> {
+        estimate: C,
+        cost: C,
+        node: N,
+    }
+
+    impl<N, C: Ord> Ord for State<N, C> {
+        fn cmp(&self, other: &Self) -> Ordering {
+            other.estimate.cmp(&self.estimate)
+        }
+    }
+
+    impl<N, C: Ord> PartialOrd for State<N, C> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl<N, C: Ord> PartialEq for State<N, C> {
+        fn eq(&self, other: &Self) -> bool {
+            self.cmp(other) == Ordering::Equal
+        }
+    }
+
+    impl<N, C: Ord> Eq for State<N, C> {}
+
+    let mut search = Search {
+        distances: HashMap::from([(start.clone(), C::default())]),
+        predecessors: HashMap::new(),
+        goal: None,
+    };
+    let mut next_nodes = BinaryHeap::from([State {
+        estimate: heuristic(&start),
+        cost: C::default(),
+        node: start,
+    }]);
+
+    while let Some(State { cost, node, .. }) = next_nodes.pop() {
+        if cost > search.distances[&node] {
+            // already popped with a lower cost
+            continue;
+        }
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        for (next, step) in successors(&node) {
+            let alt = cost + step;
+            if search.distances.get(&next).is_some_and(|dist| *dist <= alt) {
+                continue;
+            }
+
+            search.distances.insert(next.clone(), alt);
+            search.predecessors.insert(next.clone(), node.clone());
+            next_nodes.push(State {
+                estimate: alt + heuristic(&next),
+                cost: alt,
+                node: next,
+            });
+        }
+    }
+
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 3 costs 1 + 5, 0 -> 2 -> 3 costs 2 + 1, 4 is unreachable
+    fn edges(node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            0 => vec![(1, 1), (2, 2)],
+            1 => vec![(3, 5)],
+            2 => vec![(3, 1)],
+            4 => vec![(0, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn weighted() {
+        let search = dijkstra(0, edges, |n| *n == 3);
+        assert_eq!(search.goal, Some(3));
+        assert_eq!(search.goal_distance(), Some(3));
+        assert_eq!(search.goal_path(), Some(vec![0, 2, 3]));
+
+        let search = dijkstra(0, edges, |_| false);
+        assert_eq!(search.goal, None);
+        assert_eq!(search.distances.len(), 4);
+        assert_eq!(search.distance(&1), Some(1));
+        assert_eq!(search.path_to(&4), None);
+        assert_eq!(search.path_to(&0), Some(vec![0]));
+    }
+
+    #[test]
+    fn unweighted() {
+        let search = bfs(0, |n| edges(n).into_iter().map(|(next, _)| next), |_| false);
+        assert_eq!(search.distance(&3), Some(2));
+        assert_eq!(search.path_to(&3), Some(vec![0, 1, 3]));
+
+        // stops as soon as the goal comes off the queue
+        let search = bfs(0_i32, |n| [n - 1, n + 1], |n| *n == 3);
+        assert_eq!(search.goal_distance(), Some(3));
+        assert_eq!(search.goal_path(), Some(vec![0, 1, 2, 3]));
+        assert!(search.distances.len() < 10);
+    }
+
+    #[test]
+    fn heuristic() {
+        // shortest path on an open 10x10 grid, manhattan distance as heuristic
+        let neighbours = |&(x, y): &(i32, i32)| {
+            [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+                .into_iter()
+                .filter(|(x, y)| (0..10).contains(x) && (0..10).contains(y))
+                .map(|p| (p, 1))
+        };
+        let target = (9, 9);
+        let manhattan = |&(x, y): &(i32, i32)| (target.0 - x).abs() + (target.1 - y).abs();
+
+        let guided = astar((0, 0), neighbours, manhattan, |p| *p == target);
+        let plain = dijkstra((0, 0), neighbours, |p| *p == target);
+        assert_eq!(guided.goal_distance(), Some(18));
+        assert_eq!(plain.goal_distance(), Some(18));
+        assert_eq!(guided.goal_path().map(|p| p.len()), Some(19));
+        assert!(guided.distances.len() <= plain.distances.len());
+    }
+}
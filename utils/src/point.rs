@@ -0,0 +1,204 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::Point;
+
+// signed coordinates, for maps that extend past the origin or have no bounds
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IPoint {
+    pub x: i64,
+    pub y: i64,
+}
+
+// the same type, for when it's used as a displacement rather than a position
+pub type Vec2 = IPoint;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IPoint3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+pub type Vec3 = IPoint3;
+
+// component-wise arithmetic, plus scaling by an i64
+macro_rules! point_ops {
+    ($point:ident, $($field:ident),*) => {
+        impl Add for $point {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($field: self.$field + other.$field),* }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($field: self.$field - other.$field),* }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),* }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = Self;
+
+            fn mul(self, factor: i64) -> Self {
+                Self { $($field: self.$field * factor),* }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl $point {
+            pub fn manhattan(self, other: Self) -> i64 {
+                0 $(+ (self.$field - other.$field).abs())*
+            }
+        }
+    };
+}
+
+point_ops!(IPoint, x, y);
+point_ops!(IPoint3, x, y, z);
+
+impl IPoint {
+    pub const ORIGIN: Self = Self::new(0, 0);
+    pub const UP: Self = Self::new(0, -1);
+    pub const RIGHT: Self = Self::new(1, 0);
+    pub const DOWN: Self = Self::new(0, 1);
+    pub const LEFT: Self = Self::new(-1, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    // the 4 orthogonal neighbours, clockwise from up
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        [Self::UP, Self::RIGHT, Self::DOWN, Self::LEFT]
+            .into_iter()
+            .map(move |d| self + d)
+    }
+
+    // all 8 neighbours, row by row
+    pub fn adjacents(self) -> impl Iterator<Item = Self> {
+        (-1..=1)
+            .flat_map(|y| (-1..=1).map(move |x| Self::new(x, y)))
+            .filter(|d| *d != Self::ORIGIN)
+            .map(move |d| self + d)
+    }
+
+    // euclidean remainder of both coordinates, maps an infinite plane onto a tile
+    pub fn rem_euclid(self, width: i64, height: i64) -> Self {
+        Self::new(self.x.rem_euclid(width), self.y.rem_euclid(height))
+    }
+}
+
+impl IPoint3 {
+    pub const ORIGIN: Self = Self::new(0, 0, 0);
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    // the 6 neighbours sharing a face
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        [
+            Self::new(-1, 0, 0),
+            Self::new(1, 0, 0),
+            Self::new(0, -1, 0),
+            Self::new(0, 1, 0),
+            Self::new(0, 0, -1),
+            Self::new(0, 0, 1),
+        ]
+        .into_iter()
+        .map(move |d| self + d)
+    }
+
+    pub fn xy(self) -> IPoint {
+        IPoint::new(self.x, self.y)
+    }
+}
+
+impl From<Point> for IPoint {
+    fn from(point: Point) -> Self {
+        Self::new(point.x as i64, point.y as i64)
+    }
+}
+
+// fails for negative coordinates
+impl TryFrom<IPoint> for Point {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(point: IPoint) -> Result<Self, Self::Error> {
+        Ok(Point {
+            x: point.x.try_into()?,
+            y: point.y.try_into()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let mut p = IPoint::new(2, -3);
+        assert_eq!(p + IPoint::RIGHT * 4, IPoint::new(6, -3));
+        assert_eq!(-p, IPoint::new(-2, 3));
+        p -= IPoint::new(5, 5);
+        assert_eq!(p, IPoint::new(-3, -8));
+        assert_eq!(p.manhattan(IPoint::ORIGIN), 11);
+        assert_eq!(p.rem_euclid(5, 5), IPoint::new(2, 2));
+
+        let q = IPoint3::new(1, 2, 3) + IPoint3::new(1, 1, 1) * -2;
+        assert_eq!(q, IPoint3::new(-1, 0, 1));
+        assert_eq!(q.manhattan(IPoint3::ORIGIN), 2);
+        assert_eq!(q.xy(), IPoint::new(-1, 0));
+    }
+
+    #[test]
+    fn neighbours() {
+        let p = IPoint::new(0, 0);
+        assert_eq!(
+            p.neighbours().collect::<Vec<_>>(),
+            [
+                IPoint::new(0, -1),
+                IPoint::new(1, 0),
+                IPoint::new(0, 1),
+                IPoint::new(-1, 0)
+            ]
+        );
+        assert_eq!(p.adjacents().count(), 8);
+        assert!(p.adjacents().all(|a| a != p && a.manhattan(p) <= 2));
+        assert!(IPoint3::ORIGIN
+            .neighbours()
+            .all(|n| n.manhattan(IPoint3::ORIGIN) == 1));
+    }
+
+    #[test]
+    fn conversions() {
+        let p = Point { x: 3, y: 4 };
+        assert_eq!(IPoint::from(p), IPoint::new(3, 4));
+        assert_eq!(Point::try_from(IPoint::new(3, 4)), Ok(p));
+        assert!(Point::try_from(IPoint::new(-1, 4)).is_err());
+    }
+}
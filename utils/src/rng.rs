@@ -0,0 +1,38 @@
+// a small seeded linear congruential generator, only built for tests (the `rng`
+// feature) since its output is far too predictable for anything else
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lcg(u64);
+
+impl Lcg {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    // the whole state, whose low bits are far from random
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        self.0
+    }
+
+    // something in 0..n from the better high bits, n has to be above 0
+    pub fn below(&mut self, n: u64) -> u64 {
+        (self.next_u64() >> 33) % n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeatable() {
+        let (mut a, mut b) = (Lcg::new(7), Lcg::new(7));
+        let values: Vec<_> = (0..100).map(|_| a.below(10)).collect();
+        assert_eq!(values, (0..100).map(|_| b.below(10)).collect::<Vec<_>>());
+        assert!((0..10).all(|n| values.contains(&n)));
+        assert_ne!(Lcg::new(1).next_u64(), Lcg::new(2).next_u64());
+    }
+}
@@ -0,0 +1,131 @@
+use std::{fmt, process};
+
+use crate::{input, parse::ParseError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Signed(i128),
+    Unsigned(u128),
+    Text(String),
+    // the day has no such part (day 25 part 2)
+    Unsolved,
+    // the input has no answer, e.g. a path that never arrives
+    Error(String),
+}
+
+macro_rules! answer_from {
+    ($variant:ident, $wide:ty, $($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    Answer::$variant(value as $wide)
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(Signed, i128, i8, i16, i32, i64, i128, isize);
+answer_from!(Unsigned, u128, u8, u16, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+// parts that can fail report the error instead of an answer
+impl<T: Into<Answer>, E: fmt::Display> From<Result<T, E>> for Answer {
+    fn from(value: Result<T, E>) -> Self {
+        match value {
+            Ok(answer) => answer.into(),
+            Err(e) => Answer::Error(e.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(val) => write!(f, "{val}"),
+            Answer::Unsigned(val) => write!(f, "{val}"),
+            Answer::Text(val) => write!(f, "{val}"),
+            Answer::Unsolved => write!(f, "-"),
+            Answer::Error(e) => write!(f, "error: {e}"),
+        }
+    }
+}
+
+pub trait Solution {
+    const DAY: u32;
+
+    // output of parse, may borrow from the input text
+    type Parsed<'a>;
+
+    // anything a day needs besides its input, e.g. a step count
+    type Params;
+    // values for the actual puzzle, the examples often use others
+    const PARAMS: Self::Params;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError>;
+
+    fn part1(parsed: &Self::Parsed<'_>, params: &Self::Params) -> Answer;
+
+    fn part2(_parsed: &Self::Parsed<'_>, _params: &Self::Params) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+// entry point for the per day binaries
+pub fn run<S: Solution>() {
+    let input = input::from_args(S::DAY).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+
+    let parsed = S::parse(&input).unwrap_or_else(|e| {
+        eprintln!("{}", e.render(&input));
+        process::exit(1);
+    });
+    let part1 = S::part1(&parsed, &S::PARAMS);
+    println!("part 1: {part1}");
+    let part2 = S::part2(&parsed, &S::PARAMS);
+    if part2 != Answer::Unsolved {
+        println!("part 2: {part2}");
+    }
+    if matches!(part1, Answer::Error(_)) || matches!(part2, Answer::Error(_)) {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answer_display() {
+        assert_eq!(Answer::from(42_u8).to_string(), "42");
+        assert_eq!(Answer::from(-7_i32).to_string(), "-7");
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from(i128::MIN).to_string(), i128::MIN.to_string());
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+        assert_eq!(Answer::Unsolved.to_string(), "-");
+        assert_eq!(Answer::from(Ok::<_, String>(3_u8)), Answer::Unsigned(3));
+        assert_eq!(
+            Answer::from(Err::<u8, _>("no path")).to_string(),
+            "error: no path"
+        );
+    }
+
+    #[test]
+    fn answer_widths() {
+        assert_eq!(Answer::from(5_usize), Answer::from(5_u16));
+        assert_eq!(Answer::from(5_i64), Answer::Signed(5));
+    }
+}